@@ -1,5 +1,8 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use dispnet_hash::{DispnetHash, HashType, HashConfig};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use dispnet_hash::{DispnetHash, DispnetHasher, HashType, HashConfig};
+
+const THROUGHPUT_SIZES: [usize; 5] = [1 << 10, 64 << 10, 1 << 20, 16 << 20, 100 << 20];
+const STREAM_CHUNK_SIZE: usize = 64 << 10;
 
 
 fn new_hash() {
@@ -21,7 +24,7 @@ fn new_argon2_hash() {
 }
 
 fn new_argon2_salt_hash() {
-    let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
+    let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
     let display_hash = format!("{}", dispnet_hash);
     assert_eq!(display_hash, "030084246172676f6e326924763d3139246d3d343039362c743d332c703d31244d54497a4e4455324e7a6724686f56354d494638596a39746b39356c467365546279554a6e393336484944586754685533637065643151");
 }
@@ -101,5 +104,33 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("encoded u64", |b| b.iter(encoded_u64));
 }
 
-criterion_group!(benches, criterion_benchmark);
+fn stream_hash(hash_type: HashType, data: &[u8]) -> DispnetHash {
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    for chunk in data.chunks(STREAM_CHUNK_SIZE) {
+        hasher.update(chunk);
+    }
+    hasher.finalize()
+}
+
+fn throughput_benchmark(c: &mut Criterion) {
+    let data: Vec<u8> = (0..THROUGHPUT_SIZES[THROUGHPUT_SIZES.len() - 1]).map(|i| (i % 251) as u8).collect();
+    for (name, hash_type) in [("Blake3", HashType::Blake3), ("CRC32", HashType::CRC), ("Git SHA-1", HashType::GitSha1)] {
+        let mut group = c.benchmark_group(format!("{} throughput", name));
+        group.sample_size(10);
+        for size in THROUGHPUT_SIZES {
+            group.throughput(Throughput::Bytes(size as u64));
+            group.bench_with_input(BenchmarkId::new("create", size), &data[..size], |b, data| {
+                b.iter(|| DispnetHash::create(hash_type, data, None))
+            });
+            if hash_type != HashType::GitSha1 {
+                group.bench_with_input(BenchmarkId::new("stream", size), &data[..size], |b, data| {
+                    b.iter(|| stream_hash(hash_type, data))
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, criterion_benchmark, throughput_benchmark);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,55 @@
+use dispnet_hash::{DispnetHash, HashConfig, HashType};
+use napi::{bindgen_prelude::Buffer, Error, Result, Status};
+use napi_derive::napi;
+
+/// Parts of a parsed dispnet hash.
+#[napi(object)]
+pub struct ParsedHash {
+    /// Hash type code (1 = Blake3, 2 = CRC, 3 = Argon2, 4 = SimHash, 5 = Git SHA-1, 6 = CRC-32 IEEE, 7 = CRC-32 Koopman).
+    pub hash_type: u32,
+    pub digest_length: u32,
+    pub digest_value: Buffer,
+}
+
+fn hash_type(code: u32) -> Result<HashType> {
+    match code {
+        1 => Ok(HashType::Blake3),
+        2 => Ok(HashType::CRC),
+        3 => Ok(HashType::Argon2),
+        4 => Ok(HashType::SimHash),
+        5 => Ok(HashType::GitSha1),
+        6 => Ok(HashType::CrcIeee),
+        7 => Ok(HashType::CrcKoopman),
+        _ => Err(Error::new(Status::InvalidArg, format!("Unknown hash type: {}", code))),
+    }
+}
+
+fn type_code(hash_type: HashType) -> u32 {
+    hash_type.to_string().parse().unwrap_or_default()
+}
+
+/// Create a hash and return its display format, the salt is only used by Argon2.
+#[napi]
+pub fn create_hash(hash_type_code: u32, value: Buffer, salt: Option<Buffer>) -> Result<String> {
+    let config = salt.map(|salt| HashConfig::default().with_salt(salt.to_vec()));
+    Ok(DispnetHash::create(hash_type(hash_type_code)?, &value, config).to_string())
+}
+
+/// Parse the display format of a hash.
+#[napi]
+pub fn parse_hash(hash: String) -> Result<ParsedHash> {
+    let dispnet_hash = hash
+        .parse::<DispnetHash>()
+        .map_err(|error| Error::new(Status::InvalidArg, format!("Invalid dispnet hash: {:?}", error)))?;
+    Ok(ParsedHash {
+        hash_type: type_code(dispnet_hash.hash_type),
+        digest_length: dispnet_hash.digest_length as u32,
+        digest_value: dispnet_hash.digest_value.into(),
+    })
+}
+
+/// Verify the display format of a hash with the raw value.
+#[napi]
+pub fn verify_hash(hash: String, value: Buffer) -> bool {
+    DispnetHash::verify(&hash, &value)
+}
@@ -0,0 +1,120 @@
+use dispnet_hash::{DispnetHash, HashConfig, HashError};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const ERROR_KIND: &'static str = r#"
+export type DispnetHashErrorKind = "InvalidDigest" | "DigestLength" | "DigestLengthMissmatch" | "UnknownHashType" | "Other";
+"#;
+
+/// Hash type (mirrors the Rust `HashType`, the values are the type codes).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HashType {
+    Blake3 = 1,
+    CRC = 2,
+    Argon2 = 3,
+    SimHash = 4,
+    GitSha1 = 5,
+    CrcIeee = 6,
+    CrcKoopman = 7,
+}
+
+impl From<HashType> for dispnet_hash::HashType {
+    fn from(hash_type: HashType) -> Self {
+        match hash_type {
+            HashType::Blake3 => dispnet_hash::HashType::Blake3,
+            HashType::CRC => dispnet_hash::HashType::CRC,
+            HashType::Argon2 => dispnet_hash::HashType::Argon2,
+            HashType::SimHash => dispnet_hash::HashType::SimHash,
+            HashType::GitSha1 => dispnet_hash::HashType::GitSha1,
+            HashType::CrcIeee => dispnet_hash::HashType::CrcIeee,
+            HashType::CrcKoopman => dispnet_hash::HashType::CrcKoopman,
+        }
+    }
+}
+
+/// Error thrown by `parseHash`, `kind` is one of `DispnetHashErrorKind`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct DispnetHashError {
+    kind: String,
+    message: String,
+}
+
+#[wasm_bindgen]
+impl DispnetHashError {
+    #[wasm_bindgen(getter, unchecked_return_type = "DispnetHashErrorKind")]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl From<HashError> for DispnetHashError {
+    fn from(error: HashError) -> Self {
+        let kind = match error {
+            HashError::InvalidDigest { .. } => "InvalidDigest",
+            HashError::DigestLength { .. } => "DigestLength",
+            HashError::DigestLengthMissmatch { .. } => "DigestLengthMissmatch",
+            HashError::UnknownHashType { .. } => "UnknownHashType",
+            _ => "Other",
+        };
+        Self {
+            kind: kind.to_owned(),
+            message: format!("{:?}", error),
+        }
+    }
+}
+
+/// Parts of a parsed dispnet hash.
+#[wasm_bindgen]
+pub struct ParsedHash {
+    hash: DispnetHash,
+}
+
+#[wasm_bindgen]
+impl ParsedHash {
+    #[wasm_bindgen(getter, js_name = hashType)]
+    pub fn hash_type(&self) -> u8 {
+        self.hash.hash_type.to_string().parse().unwrap_or_default()
+    }
+
+    #[wasm_bindgen(getter, js_name = digestLength)]
+    pub fn digest_length(&self) -> usize {
+        self.hash.digest_length
+    }
+
+    #[wasm_bindgen(getter, js_name = digestValue)]
+    pub fn digest_value(&self) -> Vec<u8> {
+        self.hash.digest_value.clone()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_display(&self) -> String {
+        self.hash.to_string()
+    }
+}
+
+/// Create a hash and return its display format, the salt is only used by Argon2.
+#[wasm_bindgen(js_name = createHash)]
+pub fn create_hash(hash_type: HashType, value: &[u8], salt: Option<Vec<u8>>) -> String {
+    let config = salt.map(|salt| HashConfig::default().with_salt(salt));
+    DispnetHash::create(hash_type.into(), value, config).to_string()
+}
+
+/// Parse the display format of a hash, throws a `DispnetHashError` for invalid hashes.
+#[wasm_bindgen(js_name = parseHash)]
+pub fn parse_hash(hash: &str) -> Result<ParsedHash, DispnetHashError> {
+    let hash = hash.parse::<DispnetHash>()?;
+    Ok(ParsedHash { hash })
+}
+
+/// Verify the display format of a hash with the raw value.
+#[wasm_bindgen(js_name = verifyHash)]
+pub fn verify_hash(hash: &str, value: &[u8]) -> bool {
+    DispnetHash::verify(hash, value)
+}
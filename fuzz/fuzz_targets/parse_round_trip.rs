@@ -0,0 +1,15 @@
+#![no_main]
+
+use dispnet_hash::DispnetHash;
+use libfuzzer_sys::fuzz_target;
+
+// Every successfully parsed hash must display to a string which parses to an equal hash.
+fuzz_target!(|raw_hash: &str| {
+    if let Ok(dispnet_hash) = raw_hash.parse::<DispnetHash>() {
+        let display_hash = dispnet_hash.to_string();
+        let reparsed_hash = display_hash.parse::<DispnetHash>().unwrap();
+        assert_eq!(reparsed_hash, dispnet_hash);
+        assert_eq!(reparsed_hash.to_string(), display_hash);
+        assert_eq!(DispnetHash::from_bytes(&dispnet_hash.to_bytes()).unwrap(), dispnet_hash);
+    }
+});
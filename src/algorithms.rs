@@ -0,0 +1,238 @@
+use std::{fmt, str::from_utf8};
+
+use crate::{Argon2Params, CrcVariant, DispnetHash, HashError, HashType, PasswordHash};
+
+/// Blake3 hash with the digest as fixed size array.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{Blake3Hash, DispnetHash};
+///
+/// fn blake3_hash() {
+///     let blake3_hash = Blake3Hash::new("test".as_bytes());
+///     let dispnet_hash = DispnetHash::from(blake3_hash);
+///     assert_eq!(Blake3Hash::try_from(&dispnet_hash).unwrap().as_bytes(), blake3_hash.as_bytes());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Blake3Hash([u8; 32]);
+
+/// CRC-32 checksum with its variant.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{Crc32Hash, CrcVariant, DispnetHash, HashType};
+///
+/// fn crc32_hash() {
+///     let crc32_hash = Crc32Hash::with_variant(CrcVariant::Ieee, "test".as_bytes());
+///     assert_eq!(crc32_hash.checksum(), 0xd87f7e0c);
+///     assert_eq!(DispnetHash::from(crc32_hash).hash_type, HashType::CrcIeee);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc32Hash {
+    variant: CrcVariant,
+    checksum: u32,
+}
+
+/// Argon2 password hash with its parsed parameters.
+///
+/// Like [`PasswordHash`] it can only be verified, it has no equality.
+#[derive(Debug, Clone)]
+pub struct Argon2Hash {
+    hash: DispnetHash,
+    params: Argon2Params,
+}
+
+impl Blake3Hash {
+    pub fn new(value: &[u8]) -> Self {
+        Self(*blake3::hash(value).as_bytes())
+    }
+
+    pub fn from_bytes(digest: [u8; 32]) -> Self {
+        Self(digest)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Crc32Hash {
+    /// CRC-32C (Castagnoli) checksum, the default of `HashType::CRC`.
+    pub fn new(value: &[u8]) -> Self {
+        Self::with_variant(CrcVariant::Castagnoli, value)
+    }
+
+    pub fn with_variant(variant: CrcVariant, value: &[u8]) -> Self {
+        Self {
+            variant,
+            checksum: crc::Crc::<u32>::new(variant.algorithm()).checksum(value),
+        }
+    }
+
+    pub fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    pub fn variant(&self) -> CrcVariant {
+        self.variant
+    }
+}
+
+impl Argon2Hash {
+    /// Argon2 hash of the password (see [`PasswordHash::create`]).
+    pub fn create(password: &[u8], salt: &[u8]) -> Result<Self, HashError> {
+        Argon2Hash::try_from(DispnetHash::from(PasswordHash::create(password, salt)?))
+    }
+
+    /// True if the password matches the hash.
+    #[must_use]
+    pub fn verify(&self, password: &[u8]) -> bool {
+        DispnetHash::verify_instance(&self.hash, password)
+    }
+
+    pub fn params(&self) -> &Argon2Params {
+        &self.params
+    }
+
+    /// Standard PHC string (without the dispnet type and length header).
+    pub fn phc(&self) -> &str {
+        // Only hashes with a parsable UTF-8 PHC string are converted into an `Argon2Hash`.
+        from_utf8(&self.hash.digest_value).unwrap()
+    }
+}
+
+impl From<Blake3Hash> for DispnetHash {
+    fn from(blake3_hash: Blake3Hash) -> Self {
+        DispnetHash::from_digest(HashType::Blake3, blake3_hash.0.to_vec())
+    }
+}
+
+impl From<Crc32Hash> for DispnetHash {
+    fn from(crc32_hash: Crc32Hash) -> Self {
+        DispnetHash::from_digest(crc32_hash.variant.hash_type(), crc32_hash.checksum.to_string().into_bytes())
+    }
+}
+
+impl From<Argon2Hash> for DispnetHash {
+    fn from(argon2_hash: Argon2Hash) -> Self {
+        argon2_hash.hash
+    }
+}
+
+impl TryFrom<&DispnetHash> for Blake3Hash {
+    type Error = HashError;
+
+    fn try_from(hash: &DispnetHash) -> Result<Self, HashError> {
+        if hash.hash_type != HashType::Blake3 {
+            return Err(HashError::UnexpectedHashType { hash_type: hash.hash_type });
+        }
+        let digest = hash.digest_value.as_slice().try_into().map_err(|_| HashError::InvalidDigestForType {
+            hash_type: hash.hash_type,
+            length: hash.digest_length,
+        })?;
+        Ok(Self(digest))
+    }
+}
+
+impl TryFrom<&DispnetHash> for Crc32Hash {
+    type Error = HashError;
+
+    fn try_from(hash: &DispnetHash) -> Result<Self, HashError> {
+        let variant = hash
+            .hash_type
+            .crc_variant()
+            .ok_or(HashError::UnexpectedHashType { hash_type: hash.hash_type })?;
+        let checksum = hash.crc_value().ok_or(HashError::InvalidDigestForType {
+            hash_type: hash.hash_type,
+            length: hash.digest_length,
+        })?;
+        Ok(Self { variant, checksum })
+    }
+}
+
+impl TryFrom<DispnetHash> for Argon2Hash {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        if hash.hash_type != HashType::Argon2 {
+            return Err(HashError::UnexpectedHashType { hash_type: hash.hash_type });
+        }
+        let params = hash.argon2_params().ok_or(HashError::InvalidDigestForType {
+            hash_type: hash.hash_type,
+            length: hash.digest_length,
+        })?;
+        Ok(Self { hash, params })
+    }
+}
+
+impl TryFrom<DispnetHash> for Blake3Hash {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        Blake3Hash::try_from(&hash)
+    }
+}
+
+impl TryFrom<DispnetHash> for Crc32Hash {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        Crc32Hash::try_from(&hash)
+    }
+}
+
+impl fmt::Display for Blake3Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DispnetHash::from(*self).fmt(f)
+    }
+}
+
+impl fmt::Display for Crc32Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DispnetHash::from(*self).fmt(f)
+    }
+}
+
+impl fmt::Display for Argon2Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.hash.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Argon2Hash, Blake3Hash, Crc32Hash, CrcVariant, DispnetHash, HashError, HashType};
+
+    #[test]
+    fn algorithm_conversions() {
+        let blake3_hash = Blake3Hash::new("test".as_bytes());
+        assert_eq!(DispnetHash::from(blake3_hash), DispnetHash::new("test".as_bytes()));
+        assert_eq!(blake3_hash.to_string(), DispnetHash::new("test".as_bytes()).to_string());
+
+        for variant in [CrcVariant::Castagnoli, CrcVariant::Ieee, CrcVariant::Koopman] {
+            let crc32_hash = Crc32Hash::with_variant(variant, "test".as_bytes());
+            let dispnet_hash = DispnetHash::create(variant.hash_type(), "test".as_bytes(), None);
+            assert_eq!(DispnetHash::from(crc32_hash), dispnet_hash);
+            assert_eq!(Crc32Hash::try_from(&dispnet_hash).unwrap(), crc32_hash);
+        }
+
+        let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        assert!(matches!(Blake3Hash::try_from(&crc_hash), Err(HashError::UnexpectedHashType { hash_type: HashType::CRC })));
+        assert!(Crc32Hash::try_from(DispnetHash::new("test".as_bytes())).is_err());
+        assert!(Argon2Hash::try_from(crc_hash).is_err());
+    }
+
+    #[test]
+    fn argon2_hash() {
+        let argon2_hash = Argon2Hash::create(b"secret", b"12345678").unwrap();
+        assert!(argon2_hash.verify(b"secret"));
+        assert!(!argon2_hash.verify(b"wrong"));
+        assert_eq!(argon2_hash.params().memory_kib, 4096);
+        assert!(argon2_hash.phc().starts_with("$argon2i$v=19$"));
+        let dispnet_hash = DispnetHash::from(argon2_hash.clone());
+        assert_eq!(dispnet_hash.to_string(), argon2_hash.to_string());
+        assert!(Argon2Hash::try_from(dispnet_hash).unwrap().verify(b"secret"));
+    }
+}
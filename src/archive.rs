@@ -0,0 +1,163 @@
+use std::io::{self, Read, Seek};
+
+use crate::{DispnetHash, DispnetHasher, HashType};
+
+/// Hashed entry of an archive.
+struct ArchiveEntry {
+    kind: u8,
+    path: String,
+    content_hash: DispnetHash,
+}
+
+/// Hash the logical content of a tar archive (see [`hash_zip`] for the encoding).
+///
+/// Compressed archives must be decompressed by the reader (e.g. with a gzip decoder).
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_tar, HashType};
+///
+/// fn hash_tar_archive() {
+///     let archive = std::fs::File::open("release.tar").unwrap();
+///     let dispnet_hash = hash_tar(archive, HashType::Blake3).unwrap();
+/// }
+/// ```
+pub fn hash_tar(reader: impl Read, hash_type: HashType) -> io::Result<DispnetHash> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = normalize_path(&entry.path()?.to_string_lossy());
+        let entry_type = entry.header().entry_type();
+        let (kind, content_hash) = if entry_type.is_file() || entry_type.is_contiguous() {
+            (b'f', hash_content(&mut entry, hash_type)?)
+        } else if entry_type.is_symlink() || entry_type.is_hard_link() {
+            let target = entry.link_name()?.unwrap_or_default();
+            let kind = if entry_type.is_symlink() { b'l' } else { b'h' };
+            (kind, DispnetHash::create(hash_type, target.to_string_lossy().as_bytes(), None))
+        } else {
+            continue;
+        };
+        entries.push(ArchiveEntry {
+            kind,
+            path,
+            content_hash,
+        });
+    }
+    Ok(hash_entries(entries, hash_type))
+}
+
+/// Hash the logical content of a zip archive.
+///
+/// Re-compressed or re-ordered archives with the same entries produce the same hash.
+/// Only the path, the entry kind and the content of files and symlinks are hashed,
+/// directories, timestamps, owners, permissions and the compression are ignored.
+///
+/// # Encoding
+///
+/// The entries are sorted by their normalized path (`/` separated without leading `./` or `/`)
+/// and every entry is hashed as:
+///
+/// * Kind as one byte (`f` for files, `l` for symlinks, `h` for hard links).
+/// * Length of the path as u32 (big-endian) + path as UTF-8.
+/// * Binary format (see [`DispnetHash::to_bytes`]) of the content hash (the link target for links).
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_zip, HashType};
+///
+/// fn hash_zip_archive() {
+///     let archive = std::fs::File::open("release.zip").unwrap();
+///     let dispnet_hash = hash_zip(archive, HashType::Blake3).unwrap();
+/// }
+/// ```
+pub fn hash_zip(reader: impl Read + Seek, hash_type: HashType) -> io::Result<DispnetHash> {
+    let mut archive = zip::ZipArchive::new(reader).map_err(io::Error::other)?;
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(io::Error::other)?;
+        if file.is_dir() {
+            continue;
+        }
+        let kind = if file.is_symlink() { b'l' } else { b'f' };
+        let path = normalize_path(file.name());
+        let content_hash = hash_content(&mut file, hash_type)?;
+        entries.push(ArchiveEntry {
+            kind,
+            path,
+            content_hash,
+        });
+    }
+    Ok(hash_entries(entries, hash_type))
+}
+
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+fn hash_content(reader: &mut impl Read, hash_type: HashType) -> io::Result<DispnetHash> {
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    io::copy(reader, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+fn hash_entries(mut entries: Vec<ArchiveEntry>, hash_type: HashType) -> DispnetHash {
+    entries.sort_by(|a, b| a.path.as_bytes().cmp(b.path.as_bytes()));
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    for entry in entries {
+        hasher.update(&[entry.kind]);
+        hasher.update(&(entry.path.len() as u32).to_be_bytes());
+        hasher.update(entry.path.as_bytes());
+        hasher.update(&entry.content_hash.to_bytes());
+    }
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+    use crate::{hash_tar, hash_zip, HashType};
+
+    const FILES: [(&str, &[u8]); 2] = [("docs/readme.txt", b"read me"), ("data.bin", &[1, 2, 3, 4])];
+
+    fn tar_archive(files: &[(&str, &[u8])], mtime: u64) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            builder.append_data(&mut header, path, *content).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    fn zip_archive(files: &[(&str, &[u8])], compression: CompressionMethod) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.add_directory("docs/", SimpleFileOptions::default()).unwrap();
+        for (path, content) in files {
+            writer.start_file(*path, SimpleFileOptions::default().compression_method(compression)).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn hash_archive_content() {
+        let reordered: Vec<(&str, &[u8])> = FILES.iter().rev().cloned().collect();
+        let tar_hash = hash_tar(Cursor::new(tar_archive(&FILES, 1)), HashType::Blake3).unwrap();
+        assert_eq!(tar_hash, hash_tar(Cursor::new(tar_archive(&reordered, 2)), HashType::Blake3).unwrap());
+
+        let zip_hash = hash_zip(Cursor::new(zip_archive(&FILES, CompressionMethod::Stored)), HashType::Blake3).unwrap();
+        assert_eq!(zip_hash, hash_zip(Cursor::new(zip_archive(&reordered, CompressionMethod::Deflated)), HashType::Blake3).unwrap());
+        assert_eq!(zip_hash, tar_hash);
+
+        let changed: Vec<(&str, &[u8])> = vec![FILES[0], ("data.bin", &[1, 2, 3, 5])];
+        assert_ne!(tar_hash, hash_tar(Cursor::new(tar_archive(&changed, 1)), HashType::Blake3).unwrap());
+    }
+}
@@ -0,0 +1,206 @@
+//! Argon2 implementation, `rust-argon2` (default feature) or the RustCrypto `argon2` crate
+//! (`argon2-rustcrypto` feature). Both produce the same PHC strings, `rust-argon2` is used if both are enabled.
+//! On `wasm32-unknown-unknown` lanes are always hashed sequentially on the calling thread,
+//! the browser has no threads and the thread pool would block the main thread.
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+use base64::{prelude::BASE64_STANDARD_NO_PAD, Engine};
+
+use crate::Argon2Params;
+#[cfg(feature = "std")]
+use crate::Argon2ThreadPool;
+
+/// Without `std` there are no threads, lanes are always hashed sequentially.
+#[cfg(not(feature = "std"))]
+pub(crate) enum Argon2ThreadPool {}
+
+#[cfg(not(any(feature = "rust-argon2", feature = "argon2-rustcrypto")))]
+compile_error!("Enable one of the Argon2 backends: the `rust-argon2` or the `argon2-rustcrypto` feature.");
+
+#[cfg(feature = "rust-argon2")]
+use rust_argon2 as backend;
+#[cfg(all(feature = "argon2-rustcrypto", not(feature = "rust-argon2")))]
+use rustcrypto as backend;
+
+const HASH_LENGTH: usize = 32;
+/// Minimal salt length accepted by Argon2.
+#[cfg(feature = "std")]
+pub(crate) const MIN_SALT_LENGTH: usize = 8;
+const MEMORY_KIB: u32 = 4096;
+/// Argon2 requires at least 8 KiB memory per lane.
+const MAX_LANES: u32 = MEMORY_KIB / 8;
+
+/// No threads can be spawned (and no thread pool can be waited on).
+#[cfg(feature = "std")]
+const SINGLE_THREADED: bool = cfg!(all(target_arch = "wasm32", target_os = "unknown"));
+
+/// Hash the value with the default parameters of `rust-argon2`
+/// (Argon2i, version 19, 4096 KiB, 3 iterations) and the lanes (clamped to 1..=512).
+/// Lanes are hashed in parallel if the thread pool has enough threads.
+pub(crate) fn hash_encoded(value: &[u8], salt: &[u8], lanes: u32, thread_pool: Option<&Argon2ThreadPool>) -> String {
+    let params = Argon2Params {
+        variant: "argon2i".to_owned(),
+        version: 0x13,
+        memory_kib: MEMORY_KIB,
+        iterations: 3,
+        lanes: lanes.clamp(1, MAX_LANES),
+    };
+    let hash = hash_raw(&params, value, salt, HASH_LENGTH, thread_pool).unwrap();
+    format!(
+        "${}$v={}$m={},t={},p={}${}${}",
+        params.variant,
+        params.version,
+        params.memory_kib,
+        params.iterations,
+        params.lanes,
+        BASE64_STANDARD_NO_PAD.encode(salt),
+        BASE64_STANDARD_NO_PAD.encode(hash)
+    )
+}
+
+/// Verify the value with the parameters and salt of the PHC string,
+/// the lanes are limited by the default thread pool (see [`Argon2ThreadPool::set_default`]).
+pub(crate) fn verify_encoded(phc: &str, value: &[u8]) -> bool {
+    let Some(params) = Argon2Params::parse(phc) else {
+        return false;
+    };
+    let mut fields = phc.rsplit('$');
+    let (Some(raw_hash), Some(raw_salt)) = (fields.next(), fields.next()) else {
+        return false;
+    };
+    let (Ok(expected_hash), Ok(salt)) = (BASE64_STANDARD_NO_PAD.decode(raw_hash), BASE64_STANDARD_NO_PAD.decode(raw_salt))
+    else {
+        return false;
+    };
+    match hash_raw(&params, value, &salt, expected_hash.len(), None) {
+        // Compare every byte, the time must not depend on the position of the first difference.
+        Some(hash) => hash.iter().zip(&expected_hash).fold(0, |difference, (a, b)| difference | (a ^ b)) == 0,
+        None => false,
+    }
+}
+
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn hash_raw(
+    params: &Argon2Params,
+    value: &[u8],
+    salt: &[u8],
+    hash_length: usize,
+    thread_pool: Option<&Argon2ThreadPool>,
+) -> Option<Vec<u8>> {
+    #[cfg(feature = "std")]
+    if !SINGLE_THREADED && backend::PARALLEL_LANES && params.lanes > 1 {
+        let threads = Argon2ThreadPool::acquire(thread_pool, params.lanes);
+        return backend::hash_raw(params, value, salt, hash_length, threads.is_some());
+    }
+    backend::hash_raw(params, value, salt, hash_length, false)
+}
+
+#[cfg(feature = "rust-argon2")]
+mod rust_argon2 {
+    use argon2::{Config, ThreadMode, Variant, Version};
+
+    use crate::Argon2Params;
+
+    /// One thread per lane in parallel mode.
+    pub(super) const PARALLEL_LANES: bool = true;
+
+    pub(super) fn hash_raw(
+        params: &Argon2Params,
+        value: &[u8],
+        salt: &[u8],
+        hash_length: usize,
+        parallel: bool,
+    ) -> Option<Vec<u8>> {
+        let config = Config {
+            variant: Variant::from_str(&params.variant).ok()?,
+            version: Version::from_u32(params.version).ok()?,
+            mem_cost: params.memory_kib,
+            time_cost: params.iterations,
+            lanes: params.lanes,
+            thread_mode: if parallel { ThreadMode::Parallel } else { ThreadMode::Sequential },
+            hash_length: hash_length.try_into().ok()?,
+            ..Default::default()
+        };
+        argon2::hash_raw(value, salt, &config).ok()
+    }
+}
+
+// Only compared against `rust-argon2` in the tests if both backends are enabled.
+#[cfg(all(feature = "argon2-rustcrypto", any(test, not(feature = "rust-argon2"))))]
+mod rustcrypto {
+    use alloc::{vec, vec::Vec};
+
+    use argon2_rustcrypto::{Algorithm, Argon2, Params, Version};
+
+    use crate::Argon2Params;
+
+    /// Lanes are always hashed sequentially.
+    #[allow(dead_code)]
+    pub(super) const PARALLEL_LANES: bool = false;
+
+    pub(super) fn hash_raw(
+        params: &Argon2Params,
+        value: &[u8],
+        salt: &[u8],
+        hash_length: usize,
+        _parallel: bool,
+    ) -> Option<Vec<u8>> {
+        let algorithm = match params.variant.as_str() {
+            "argon2d" => Algorithm::Argon2d,
+            "argon2i" => Algorithm::Argon2i,
+            "argon2id" => Algorithm::Argon2id,
+            _ => return None,
+        };
+        let version = Version::try_from(params.version).ok()?;
+        let argon2_params = Params::new(params.memory_kib, params.iterations, params.lanes, Some(hash_length)).ok()?;
+        let mut hash = vec![0; hash_length];
+        Argon2::new(algorithm, version, argon2_params)
+            .hash_password_into(value, salt, &mut hash)
+            .ok()?;
+        Some(hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn verify_encoded() {
+        let phc = super::hash_encoded(b"test", b"12345678", 1, None);
+        assert!(phc.starts_with("$argon2i$v=19$m=4096,t=3,p=1$MTIzNDU2Nzg$"));
+        assert!(super::verify_encoded(&phc, b"test"));
+        assert!(!super::verify_encoded(&phc, b"other"));
+        assert!(!super::verify_encoded("$argon2i$v=19$m=4096,t=3,p=1$invalid", b"test"));
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn encoding_matches_rust_argon2() {
+        for lanes in [1, 4] {
+            let config = argon2::Config { lanes, ..Default::default() };
+            let phc = argon2::hash_encoded(b"test", b"12345678", &config).unwrap();
+            assert_eq!(super::hash_encoded(b"test", b"12345678", lanes, None), phc);
+            assert!(super::verify_encoded(&phc, b"test"));
+        }
+    }
+
+    #[test]
+    fn sequential_lanes_are_identical() {
+        // The single threaded path (wasm32) must produce the same hashes as the parallel lanes.
+        let params = crate::Argon2Params { variant: "argon2id".to_owned(), version: 0x13, memory_kib: 256, iterations: 2, lanes: 4 };
+        let expected = super::backend::hash_raw(&params, b"test", b"12345678", 32, true).unwrap();
+        assert_eq!(super::backend::hash_raw(&params, b"test", b"12345678", 32, false).unwrap(), expected);
+    }
+
+    #[cfg(all(feature = "rust-argon2", feature = "argon2-rustcrypto"))]
+    #[test]
+    fn backends_are_identical() {
+        use super::{rust_argon2, rustcrypto};
+        use crate::Argon2Params;
+
+        for variant in ["argon2d", "argon2i", "argon2id"] {
+            let params = Argon2Params { variant: variant.to_owned(), version: 0x13, memory_kib: 256, iterations: 2, lanes: 2 };
+            let expected = rust_argon2::hash_raw(&params, b"test", b"12345678", 32, true).unwrap();
+            assert_eq!(rustcrypto::hash_raw(&params, b"test", b"12345678", 32, false).unwrap(), expected);
+        }
+    }
+}
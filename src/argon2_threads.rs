@@ -0,0 +1,117 @@
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+static DEFAULT_POOL: OnceLock<Arc<Argon2ThreadPool>> = OnceLock::new();
+
+/// Limit for the threads Argon2 uses to hash lanes in parallel, shared by all hashes using the pool.
+///
+/// A hash with `p` lanes takes `p` threads from the pool and waits until they are available.
+/// If the pool has fewer than `p` threads in total, the lanes are hashed sequentially on the calling thread.
+/// Without a pool, lanes are always hashed in parallel (one thread per lane).
+/// The RustCrypto backend (feature `argon2-rustcrypto`) and `wasm32-unknown-unknown` always hash lanes sequentially.
+///
+/// # Usage
+/// ```
+/// use std::sync::Arc;
+/// use dispnet_hash::{Argon2ThreadPool, DispnetHash, HashConfig, HashType};
+///
+/// fn hash_password(thread_pool: &Arc<Argon2ThreadPool>, password: &str) -> DispnetHash {
+///     let config = HashConfig::default().with_argon2_lanes(4).with_argon2_thread_pool(thread_pool.clone());
+///     DispnetHash::create(HashType::Argon2, password.as_bytes(), Some(config))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Argon2ThreadPool {
+    max_threads: u32,
+    available_threads: Mutex<u32>,
+    released: Condvar,
+}
+
+/// Threads taken from a pool, returned on drop.
+pub(crate) struct Argon2Threads<'a> {
+    pool: Option<&'a Argon2ThreadPool>,
+    threads: u32,
+}
+
+impl Argon2ThreadPool {
+    pub fn new(max_threads: u32) -> Self {
+        Self {
+            max_threads,
+            available_threads: Mutex::new(max_threads),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Use the pool for hashes without a pool in their config and for verification.
+    /// Returns `false` if a default pool is already set.
+    pub fn set_default(pool: Arc<Argon2ThreadPool>) -> bool {
+        DEFAULT_POOL.set(pool).is_ok()
+    }
+
+    /// Threads which are not used by a hash right now.
+    pub fn available_threads(&self) -> u32 {
+        *self.available_threads.lock().unwrap()
+    }
+
+    /// Take the threads for the lanes from the pool (or the default pool), `None` if the lanes must be hashed sequentially.
+    pub(crate) fn acquire(pool: Option<&Argon2ThreadPool>, lanes: u32) -> Option<Argon2Threads<'_>> {
+        let Some(pool) = pool.or_else(|| DEFAULT_POOL.get().map(Arc::as_ref)) else {
+            return Some(Argon2Threads { pool: None, threads: lanes });
+        };
+        if lanes > pool.max_threads {
+            return None;
+        }
+        let mut available_threads = pool.available_threads.lock().unwrap();
+        while *available_threads < lanes {
+            available_threads = pool.released.wait(available_threads).unwrap();
+        }
+        *available_threads -= lanes;
+        Some(Argon2Threads { pool: Some(pool), threads: lanes })
+    }
+}
+
+impl Drop for Argon2Threads<'_> {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool {
+            *pool.available_threads.lock().unwrap() += self.threads;
+            pool.released.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{Argon2ThreadPool, DispnetHash, HashConfig, HashType};
+
+    #[test]
+    fn acquire_threads() {
+        let pool = Argon2ThreadPool::new(4);
+        let threads = Argon2ThreadPool::acquire(Some(&pool), 3);
+        assert!(threads.is_some());
+        assert_eq!(pool.available_threads(), 1);
+        drop(threads);
+        assert_eq!(pool.available_threads(), 4);
+        assert!(Argon2ThreadPool::acquire(Some(&pool), 8).is_none());
+    }
+
+    #[test]
+    fn lanes_hashed_with_pool() {
+        let pool = Arc::new(Argon2ThreadPool::new(2));
+        let parallel_hash = DispnetHash::create(
+            HashType::Argon2,
+            "test".as_bytes(),
+            Some(HashConfig::default().with_argon2_lanes(2).with_argon2_thread_pool(pool.clone())),
+        );
+        let sequential_hash = DispnetHash::create(
+            HashType::Argon2,
+            "test".as_bytes(),
+            Some(HashConfig::default().with_argon2_lanes(4).with_argon2_thread_pool(pool.clone())),
+        );
+        assert_eq!(parallel_hash.argon2_params().unwrap().lanes, 2);
+        assert_eq!(sequential_hash.argon2_params().unwrap().lanes, 4);
+        assert!(DispnetHash::verify_instance(&parallel_hash, "test".as_bytes()));
+        assert!(DispnetHash::verify_instance(&sequential_hash, "test".as_bytes()));
+        assert_eq!(pool.available_threads(), 2);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    file::{hash_reader, FileStamp},
+    DispnetHash, DispnetHasher, HashType,
+};
+
+/// File hasher which remembers the hashes of the last `capacity` files (least recently used are dropped).
+///
+/// A cached hash is returned as long as size and modification time of the file are unchanged,
+/// otherwise the file is hashed again. Repeated verification runs over mostly unchanged trees
+/// only hash the changed files.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{CachedHasher, HashType};
+///
+/// fn verify_twice() {
+///     let mut cached_hasher = CachedHasher::new(1024);
+///     let first_hash = cached_hasher.hash_file("Cargo.toml", HashType::Blake3).unwrap();
+///     let second_hash = cached_hasher.hash_file("Cargo.toml", HashType::Blake3).unwrap();
+///     assert_eq!(first_hash, second_hash);
+///     assert_eq!(cached_hasher.hits(), 1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CachedHasher {
+    capacity: usize,
+    entries: HashMap<(PathBuf, u8), CacheEntry>,
+    /// Keys ordered by their last use.
+    recently_used: BTreeMap<u64, (PathBuf, u8)>,
+    last_use: u64,
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    file_stamp: FileStamp,
+    hash: DispnetHash,
+    last_use: u64,
+}
+
+impl CachedHasher {
+    /// Cache for at most `capacity` file hashes (at least 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            recently_used: BTreeMap::new(),
+            last_use: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Hash of the file content (see [`crate::hash_file`]), from the cache if the file is unchanged.
+    pub fn hash_file(&mut self, path: impl AsRef<Path>, hash_type: HashType) -> io::Result<DispnetHash> {
+        let mut file = File::open(path.as_ref())?;
+        let file_stamp = FileStamp::from_file(&file)?;
+        let key = (path.as_ref().to_path_buf(), hash_type.code());
+        self.last_use += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            if entry.file_stamp == file_stamp {
+                self.hits += 1;
+                self.recently_used.remove(&entry.last_use);
+                entry.last_use = self.last_use;
+                self.recently_used.insert(self.last_use, key);
+                return Ok(entry.hash.clone());
+            }
+        }
+
+        self.misses += 1;
+        let mut hasher = DispnetHasher::new(hash_type, None);
+        hash_reader(&mut file, &mut hasher, |_| Ok(()))?;
+        let hash = hasher.finalize();
+        let entry = CacheEntry {
+            file_stamp,
+            hash: hash.clone(),
+            last_use: self.last_use,
+        };
+        if let Some(replaced_entry) = self.entries.insert(key.clone(), entry) {
+            self.recently_used.remove(&replaced_entry.last_use);
+        }
+        self.recently_used.insert(self.last_use, key);
+        while self.entries.len() > self.capacity {
+            if let Some((_, evicted_key)) = self.recently_used.pop_first() {
+                self.entries.remove(&evicted_key);
+            }
+        }
+        Ok(hash)
+    }
+
+    /// Number of hashes returned from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of files which had to be hashed.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recently_used.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{CachedHasher, DispnetHash, HashType};
+
+    #[test]
+    fn cached_file_hashes() {
+        let directory = std::env::temp_dir().join(format!("dispnet-hash-{}-cache", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("file.txt");
+        fs::write(&path, "test").unwrap();
+
+        let mut cached_hasher = CachedHasher::new(2);
+        assert_eq!(cached_hasher.hash_file(&path, HashType::CRC).unwrap(), DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+        cached_hasher.hash_file(&path, HashType::CRC).unwrap();
+        assert_eq!((cached_hasher.hits(), cached_hasher.misses()), (1, 1));
+        // Another hash type is another entry.
+        assert_eq!(cached_hasher.hash_file(&path, HashType::Blake3).unwrap(), DispnetHash::new("test".as_bytes()));
+        assert_eq!(cached_hasher.len(), 2);
+
+        fs::write(&path, "changed").unwrap();
+        assert_eq!(cached_hasher.hash_file(&path, HashType::CRC).unwrap(), DispnetHash::create(HashType::CRC, "changed".as_bytes(), None));
+        assert_eq!(cached_hasher.misses(), 3);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn least_recently_used_eviction() {
+        let directory = std::env::temp_dir().join(format!("dispnet-hash-{}-cache-eviction", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let paths: Vec<_> = (0..3).map(|i| directory.join(format!("{}.txt", i))).collect();
+        for path in &paths {
+            fs::write(path, "test").unwrap();
+        }
+
+        let mut cached_hasher = CachedHasher::new(2);
+        cached_hasher.hash_file(&paths[0], HashType::CRC).unwrap();
+        cached_hasher.hash_file(&paths[1], HashType::CRC).unwrap();
+        cached_hasher.hash_file(&paths[0], HashType::CRC).unwrap();
+        cached_hasher.hash_file(&paths[2], HashType::CRC).unwrap();
+        assert_eq!(cached_hasher.len(), 2);
+        // The second file was used least recently and is hashed again.
+        cached_hasher.hash_file(&paths[0], HashType::CRC).unwrap();
+        cached_hasher.hash_file(&paths[1], HashType::CRC).unwrap();
+        assert_eq!((cached_hasher.hits(), cached_hasher.misses()), (2, 4));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use crate::{DispnetHash, HashError, HashType};
+
+/// Largest integer which is exactly representable as f64 (2^53).
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+impl DispnetHash {
+    /// Hash a serializable value with a canonical byte encoding.
+    ///
+    /// The value is encoded as canonical JSON (see [`DispnetHash::hash_json`]),
+    /// so the hash only depends on the data and not on field or map iteration order.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    /// use std::collections::HashMap;
+    ///
+    /// fn hash_struct() {
+    ///     let value = HashMap::from([("name", "test"), ("kind", "blob")]);
+    ///     let dispnet_hash = DispnetHash::of_serialized(&value, HashType::Blake3).unwrap();
+    ///     assert_eq!(dispnet_hash, DispnetHash::new(r#"{"kind":"blob","name":"test"}"#.as_bytes()));
+    /// }
+    /// ```
+    pub fn of_serialized<T>(value: &T, hash_type: HashType) -> Result<Self, HashError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let json_value = serde_json::to_value(value).map_err(|error| HashError::Serialization {
+            message: error.to_string(),
+        })?;
+        Ok(DispnetHash::hash_json(&json_value, hash_type))
+    }
+
+    /// Hash a JSON document in its canonical form.
+    ///
+    /// The canonical form is UTF-8 without whitespace, object keys are sorted by their UTF-8 bytes
+    /// and numbers are normalized (floats with an integral value are written as integer, e.g. `1.0` as `1`).
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn hash_json() {
+    ///     let json_1: serde_json::Value = serde_json::from_str(r#"{ "b": 1.0, "a": [true, null] }"#).unwrap();
+    ///     let json_2: serde_json::Value = serde_json::from_str(r#"{"a":[true,null],"b":1}"#).unwrap();
+    ///     assert_eq!(DispnetHash::hash_json(&json_1, HashType::Blake3), DispnetHash::hash_json(&json_2, HashType::Blake3));
+    /// }
+    /// ```
+    pub fn hash_json(value: &Value, hash_type: HashType) -> Self {
+        let mut canonical = String::new();
+        write_canonical_json(value, &mut canonical);
+        DispnetHash::create(hash_type, canonical.as_bytes(), None)
+    }
+}
+
+pub(crate) fn write_canonical_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        Value::Number(number) => write_json_number(number, out),
+        Value::String(value) => write_json_string(value, out),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(value, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_string(key, out);
+                out.push(':');
+                write_canonical_json(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_json_number(number: &Number, out: &mut String) {
+    match number.as_f64() {
+        Some(value) if number.is_f64() && value.fract() == 0.0 && value.abs() < MAX_SAFE_INTEGER => {
+            out.push_str(&(value as i64).to_string())
+        }
+        Some(value) if number.is_f64() => out.push_str(&value.to_string()),
+        _ => out.push_str(&number.to_string()),
+    }
+}
+
+fn write_json_string(value: &str, out: &mut String) {
+    // Serializing a str into JSON can't fail.
+    out.push_str(&serde_json::to_string(value).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::Serialize;
+
+    use crate::{DispnetHash, HashError, HashType};
+
+    #[derive(Serialize)]
+    struct Block {
+        id: u32,
+        tags: HashMap<String, String>,
+    }
+
+    #[test]
+    fn hash_serialized_value() {
+        let tags: HashMap<String, String> = (0..20).map(|i| (format!("key{}", i), format!("value{}", i))).collect();
+        let block = Block { id: 7, tags };
+        let sorted_tags: BTreeMap<String, String> = block.tags.clone().into_iter().collect();
+        let dispnet_hash = DispnetHash::of_serialized(&block, HashType::Blake3).unwrap();
+        let expected = format!("{{\"id\":7,\"tags\":{}}}", serde_json::to_string(&sorted_tags).unwrap());
+        assert_eq!(dispnet_hash, DispnetHash::new(expected.as_bytes()));
+    }
+
+    #[test]
+    fn hash_canonical_json() {
+        let json: serde_json::Value = serde_json::from_str(r#"{ "z": [1.0, 2.5, -0.0, 1e3], "a": "\u00e4", "m": {"y": null, "x": false} }"#).unwrap();
+        let canonical = r#"{"a":"ä","m":{"x":false,"y":null},"z":[1,2.5,0,1000]}"#;
+        assert_eq!(DispnetHash::hash_json(&json, HashType::CRC), DispnetHash::create(HashType::CRC, canonical.as_bytes(), None));
+    }
+
+    #[test]
+    fn hash_serialized_invalid_value() {
+        let value = HashMap::from([((1, 2), "tuple key")]);
+        assert!(matches!(DispnetHash::of_serialized(&value, HashType::Blake3), Err(HashError::Serialization { .. })));
+    }
+}
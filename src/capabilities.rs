@@ -0,0 +1,169 @@
+use std::{
+    sync::OnceLock,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{splitmix64, DispnetHash, HashType};
+
+/// Timed rounds per candidate of [`select_fastest`], the fastest round counts.
+const BENCHMARK_ROUNDS: usize = 3;
+
+/// Inputs from this length on are hashed with multiple threads by Blake3 (feature `rayon`),
+/// below the thread overhead is larger than the gain.
+#[cfg(feature = "rayon")]
+pub(crate) const BLAKE3_PARALLEL_MIN_LEN: usize = 128 * 1024;
+
+/// Accelerated code paths available on the running machine, detected once at runtime.
+///
+/// The fastest path is selected automatically:
+///
+/// * Blake3 uses the best SIMD instruction set (`simd`).
+/// * Blake3 hashes large inputs with `blake3_threads` threads (only with the `rayon` feature).
+/// * Git SHA-1 uses the SHA extensions of the CPU (`sha_extensions`).
+/// * Hex encoding processes 8 bytes per step (in a 128 bit register).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::capabilities;
+///
+/// fn log_capabilities() {
+///     let capabilities = capabilities();
+///     println!("SIMD: {}, SHA extensions: {}, Blake3 threads: {}", capabilities.simd, capabilities.sha_extensions, capabilities.blake3_threads);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Widest SIMD instruction set: `avx512`, `avx2`, `sse4.1`, `sse2`, `neon` or `portable`.
+    pub simd: &'static str,
+    /// The CPU has SHA extensions (x86 SHA-NI or ARMv8 SHA2).
+    pub sha_extensions: bool,
+    /// Threads used to hash large Blake3 inputs, 1 without the `rayon` feature.
+    pub blake3_threads: usize,
+}
+
+/// Detect the accelerated code paths of the running machine (see [`Capabilities`]).
+pub fn capabilities() -> Capabilities {
+    static CAPABILITIES: OnceLock<Capabilities> = OnceLock::new();
+    *CAPABILITIES.get_or_init(|| Capabilities {
+        simd: detect_simd(),
+        sha_extensions: detect_sha_extensions(),
+        blake3_threads: if cfg!(feature = "rayon") {
+            thread::available_parallelism().map_or(1, |threads| threads.get())
+        } else {
+            1
+        },
+    })
+}
+
+/// Benchmark the candidates on the running machine and return the fastest one.
+///
+/// Every candidate hashes `sample_size` pseudo random bytes once to warm up and then
+/// three times, the fastest round is compared. Only pass acceptable candidates,
+/// e.g. a CRC is always faster than Blake3 but it is no integrity protection against attackers.
+/// Returns `None` if there are no candidates.
+/// # Usage
+/// ```
+/// use dispnet_hash::{select_fastest, HashType};
+///
+/// fn ingestion_hash_type() -> HashType {
+///     select_fastest(&[HashType::Blake3, HashType::GitSha1], 1024 * 1024).unwrap()
+/// }
+/// ```
+pub fn select_fastest(candidates: &[HashType], sample_size: usize) -> Option<HashType> {
+    let mut state = sample_size as u64;
+    let sample: Vec<u8> = (0..sample_size).map(|_| splitmix64(&mut state) as u8).collect();
+    candidates
+        .iter()
+        .map(|hash_type| (*hash_type, benchmark(*hash_type, &sample)))
+        .min_by_key(|(_, duration)| *duration)
+        .map(|(hash_type, _)| hash_type)
+}
+
+fn benchmark(hash_type: HashType, sample: &[u8]) -> Duration {
+    let _ = DispnetHash::create(hash_type, sample, None);
+    (0..BENCHMARK_ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            let dispnet_hash = DispnetHash::create(hash_type, sample, None);
+            let duration = start.elapsed();
+            std::hint::black_box(dispnet_hash);
+            duration
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn detect_simd() -> &'static str {
+    if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512vl") {
+        "avx512"
+    } else if is_x86_feature_detected!("avx2") {
+        "avx2"
+    } else if is_x86_feature_detected!("sse4.1") {
+        "sse4.1"
+    } else if is_x86_feature_detected!("sse2") {
+        "sse2"
+    } else {
+        "portable"
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_simd() -> &'static str {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        "neon"
+    } else {
+        "portable"
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn detect_simd() -> &'static str {
+    "portable"
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn detect_sha_extensions() -> bool {
+    is_x86_feature_detected!("sha")
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_sha_extensions() -> bool {
+    std::arch::is_aarch64_feature_detected!("sha2")
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn detect_sha_extensions() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{capabilities, select_fastest, DispnetHash, HashType};
+
+    #[test]
+    fn capabilities_report() {
+        let capabilities = capabilities();
+        assert!(["avx512", "avx2", "sse4.1", "sse2", "neon", "portable"].contains(&capabilities.simd));
+        assert!(capabilities.blake3_threads >= 1);
+        if cfg!(not(feature = "rayon")) {
+            assert_eq!(capabilities.blake3_threads, 1);
+        }
+    }
+
+    #[test]
+    fn large_blake3_input() {
+        let data: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let dispnet_hash = DispnetHash::create(HashType::Blake3, &data, None);
+        assert_eq!(dispnet_hash.digest_value, blake3::hash(&data).as_bytes());
+    }
+
+    #[test]
+    fn select_fastest_candidate() {
+        assert_eq!(select_fastest(&[], 1024), None);
+        assert_eq!(select_fastest(&[HashType::GitSha1], 1024), Some(HashType::GitSha1));
+        // Argon2 is slow by design.
+        assert_eq!(select_fastest(&[HashType::Argon2, HashType::CRC], 64 * 1024), Some(HashType::CRC));
+    }
+}
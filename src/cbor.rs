@@ -0,0 +1,91 @@
+use ciborium::Value;
+use serde::Serialize;
+
+use crate::{DispnetHash, HashError, HashType};
+
+impl DispnetHash {
+    /// Hash a serializable value after deterministic CBOR encoding (RFC 8949 section 4.2.1).
+    ///
+    /// Integers, floats and lengths use their shortest form, all lengths are definite and
+    /// map keys are sorted by the bytewise order of their encoding.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    /// use std::collections::HashMap;
+    ///
+    /// fn hash_cbor_value() {
+    ///     let value = HashMap::from([("name", "test"), ("kind", "blob")]);
+    ///     let dispnet_hash = DispnetHash::of_cbor(&value, HashType::Blake3).unwrap();
+    /// }
+    /// ```
+    pub fn of_cbor<T>(value: &T, hash_type: HashType) -> Result<Self, HashError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let cbor_value = Value::serialized(value).map_err(|error| HashError::Serialization {
+            message: error.to_string(),
+        })?;
+        Ok(DispnetHash::create(hash_type, &deterministic_cbor(cbor_value), None))
+    }
+
+    /// Hash an encoded CBOR message after re-encoding it deterministically (see [`DispnetHash::of_cbor`]).
+    /// Equal messages produce the same hash even if the sender didn't use the deterministic encoding.
+    pub fn hash_cbor(message: &[u8], hash_type: HashType) -> Result<Self, HashError> {
+        let cbor_value: Value = ciborium::from_reader(message).map_err(|error| HashError::Serialization {
+            message: error.to_string(),
+        })?;
+        Ok(DispnetHash::create(hash_type, &deterministic_cbor(cbor_value), None))
+    }
+}
+
+fn deterministic_cbor(value: Value) -> Vec<u8> {
+    encode(&canonicalize(value))
+}
+
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        Value::Tag(tag, value) => Value::Tag(tag, Box::new(canonicalize(*value))),
+        Value::Map(entries) => {
+            let mut entries: Vec<(Vec<u8>, Value, Value)> = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = canonicalize(key);
+                    (encode(&key), key, canonicalize(value))
+                })
+                .collect();
+            entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+            Value::Map(entries.into_iter().map(|(_, key, value)| (key, value)).collect())
+        }
+        value => value,
+    }
+}
+
+fn encode(value: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // Writing into a Vec can't fail.
+    ciborium::into_writer(value, &mut bytes).unwrap();
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{DispnetHash, HashError, HashType};
+
+    #[test]
+    fn hash_deterministic_cbor() {
+        let value: HashMap<String, f64> = (0..20).map(|i| (format!("key{}", i), i as f64)).collect();
+        let dispnet_hash = DispnetHash::of_cbor(&value, HashType::Blake3).unwrap();
+        let mut message = Vec::new();
+        ciborium::into_writer(&value, &mut message).unwrap();
+        assert_eq!(dispnet_hash, DispnetHash::hash_cbor(&message, HashType::Blake3).unwrap());
+
+        // {"b": 1.0, "a": 1} with a non shortest float and unsorted keys
+        let message = [0xa2, 0x61, 0x62, 0xfb, 0x3f, 0xf0, 0, 0, 0, 0, 0, 0, 0x61, 0x61, 0x01];
+        let deterministic = [0xa2, 0x61, 0x61, 0x01, 0x61, 0x62, 0xf9, 0x3c, 0x00];
+        assert_eq!(DispnetHash::hash_cbor(&message, HashType::CRC).unwrap(), DispnetHash::create(HashType::CRC, &deterministic, None));
+        assert!(matches!(DispnetHash::hash_cbor(&[0xa2], HashType::CRC), Err(HashError::Serialization { .. })));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{chunk_ranges, hasher::StateReader, DispnetHash, HashError, HashType};
+
+const CHUNK_MANIFEST_MAGIC: &[u8] = b"DCM1";
+
+/// Ordered chunks of a file with the hash of the whole file.
+///
+/// # Binary format is structured as followed:
+///
+/// * `DCM1` as magic bytes.
+/// * File hash as length u16 (big-endian) + compact binary format (see [`DispnetHash::to_bytes`]).
+/// * Number of chunks as u32 (big-endian).
+/// * Per chunk the hash as length u16 + compact binary format and the chunk length as u64 (big-endian).
+///
+/// With the feature `serde` the manifest can also be (de)serialized, e.g. as JSON.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{ChunkManifest, HashType};
+///
+/// fn chunks_to_send(local_file: &[u8], remote_manifest: &[u8]) -> usize {
+///     let local_manifest = ChunkManifest::from_data(local_file, HashType::Blake3, 8192);
+///     let remote_manifest = ChunkManifest::from_bytes(remote_manifest).unwrap();
+///     local_manifest.missing_chunks(&remote_manifest).len()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChunkManifest {
+    pub file_hash: DispnetHash,
+    pub chunks: Vec<ManifestChunk>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ManifestChunk {
+    pub hash: DispnetHash,
+    pub length: u64,
+}
+
+impl ChunkManifest {
+    /// Split the data with [`chunk_ranges`] and hash the chunks and the whole data.
+    pub fn from_data(data: &[u8], hash_type: HashType, average_chunk_size: usize) -> Self {
+        let chunks = chunk_ranges(data, average_chunk_size)
+            .into_iter()
+            .map(|range| ManifestChunk {
+                length: range.len() as u64,
+                hash: DispnetHash::create(hash_type, &data[range], None),
+            })
+            .collect();
+        Self {
+            file_hash: DispnetHash::create(hash_type, data, None),
+            chunks,
+        }
+    }
+
+    /// Total length of all chunks.
+    pub fn file_length(&self) -> u64 {
+        self.chunks.iter().map(|chunk| chunk.length).sum()
+    }
+
+    /// Chunks (without duplicates, in manifest order) which are not part of the other manifest.
+    pub fn missing_chunks(&self, other: &ChunkManifest) -> Vec<&ManifestChunk> {
+        let mut known: HashSet<Vec<u8>> = other.chunks.iter().map(|chunk| chunk.hash.to_bytes()).collect();
+        self.chunks
+            .iter()
+            .filter(|chunk| known.insert(chunk.hash.to_bytes()))
+            .collect()
+    }
+
+    /// Compact binary format (see [`ChunkManifest`]).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = CHUNK_MANIFEST_MAGIC.to_vec();
+        push_hash(&mut bytes, &self.file_hash);
+        bytes.extend_from_slice(&(self.chunks.len() as u32).to_be_bytes());
+        for chunk in &self.chunks {
+            push_hash(&mut bytes, &chunk.hash);
+            bytes.extend_from_slice(&chunk.length.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Parse the compact binary format (see [`ChunkManifest`]), trailing bytes are rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HashError> {
+        let invalid_manifest = || HashError::Serialization {
+            message: "invalid chunk manifest".to_owned(),
+        };
+        let mut reader = StateReader(bytes);
+        if reader.take(CHUNK_MANIFEST_MAGIC.len()) != Some(CHUNK_MANIFEST_MAGIC) {
+            return Err(invalid_manifest());
+        }
+        let file_hash = take_hash(&mut reader).ok_or_else(invalid_manifest)??;
+        let chunk_count = reader.take_u32().ok_or_else(invalid_manifest)? as usize;
+        // Every chunk needs at least 13 bytes, the count can not allocate more than the input.
+        let mut chunks = Vec::with_capacity(chunk_count.min(reader.0.len() / 13));
+        for _ in 0..chunk_count {
+            let hash = take_hash(&mut reader).ok_or_else(invalid_manifest)??;
+            let length = reader.take_u64().ok_or_else(invalid_manifest)?;
+            chunks.push(ManifestChunk { hash, length });
+        }
+        if !reader.0.is_empty() {
+            return Err(invalid_manifest());
+        }
+        Ok(Self { file_hash, chunks })
+    }
+
+    /// Manifest as JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Parse a manifest from JSON (see [`ChunkManifest::to_json`]).
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, HashError> {
+        serde_json::from_str(json).map_err(|error| HashError::Serialization {
+            message: error.to_string(),
+        })
+    }
+}
+
+fn push_hash(bytes: &mut Vec<u8>, hash: &DispnetHash) {
+    let hash_bytes = hash.to_bytes();
+    bytes.extend_from_slice(&(hash_bytes.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(&hash_bytes);
+}
+
+fn take_hash(reader: &mut StateReader) -> Option<Result<DispnetHash, HashError>> {
+    let length = reader.take_u16()? as usize;
+    reader.take(length).map(DispnetHash::from_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkManifest, HashError, HashType};
+
+    fn content(length: usize) -> Vec<u8> {
+        let mut state = 3;
+        (0..length).map(|_| (crate::splitmix64(&mut state) >> 56) as u8).collect()
+    }
+
+    #[test]
+    fn chunk_manifest_bytes() {
+        let data = content(100_000);
+        let manifest = ChunkManifest::from_data(&data, HashType::Blake3, 4096);
+        assert_eq!(manifest.file_length(), data.len() as u64);
+        let bytes = manifest.to_bytes();
+        assert_eq!(ChunkManifest::from_bytes(&bytes).unwrap(), manifest);
+        assert!(matches!(ChunkManifest::from_bytes(&bytes[..bytes.len() - 1]), Err(HashError::Serialization { .. })));
+        assert!(matches!(ChunkManifest::from_bytes(&[bytes.as_slice(), &[0]].concat()), Err(HashError::Serialization { .. })));
+        assert!(matches!(ChunkManifest::from_bytes(b"DCM2"), Err(HashError::Serialization { .. })));
+    }
+
+    #[test]
+    fn missing_chunks() {
+        let data = content(100_000);
+        let mut edited = data.clone();
+        edited[50_000] ^= 0xff;
+        let manifest = ChunkManifest::from_data(&data, HashType::Blake3, 4096);
+        let edited_manifest = ChunkManifest::from_data(&edited, HashType::Blake3, 4096);
+        let missing_chunks = edited_manifest.missing_chunks(&manifest);
+        assert!(!missing_chunks.is_empty() && missing_chunks.len() <= 2);
+        assert!(manifest.missing_chunks(&manifest).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn chunk_manifest_json() {
+        let manifest = ChunkManifest::from_data(&content(20_000), HashType::CRC, 4096);
+        let json = manifest.to_json();
+        assert!(json.starts_with(r#"{"file_hash":"02"#));
+        assert_eq!(ChunkManifest::from_json(&json).unwrap(), manifest);
+        assert!(matches!(ChunkManifest::from_json("{}"), Err(HashError::Serialization { .. })));
+    }
+}
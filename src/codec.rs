@@ -0,0 +1,167 @@
+use std::io;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{DispnetHash, HashType};
+
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// Codec which frames messages with a length prefix and a trailing hash of the message.
+///
+/// # Frame format is structured as followed:
+///
+/// * Message length as u32 (big-endian).
+/// * Message bytes.
+/// * Hash of the message in the compact binary format (see [`DispnetHash::to_bytes`]).
+///
+/// Decoding verifies the hash and returns an `InvalidData` error for corrupted messages,
+/// messages hashed with another hash type than the codec and messages longer than the maximum
+/// frame length (8 MiB by default).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{HashFramedCodec, HashType};
+/// use tokio::io::{AsyncRead, AsyncWrite};
+/// use tokio_util::codec::Framed;
+///
+/// fn transport<T: AsyncRead + AsyncWrite>(stream: T) -> Framed<T, HashFramedCodec> {
+///     Framed::new(stream, HashFramedCodec::new(HashType::Blake3))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashFramedCodec {
+    hash_type: HashType,
+    max_frame_length: usize,
+}
+
+impl HashFramedCodec {
+    pub fn new(hash_type: HashType) -> Self {
+        Self {
+            hash_type,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        }
+    }
+
+    /// Limit the message length, longer messages are rejected by the encoder and decoder.
+    /// The limit is clamped to `u32::MAX`, the length prefix is a u32.
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length.min(u32::MAX as usize);
+        self
+    }
+
+    fn check_length(&self, length: usize) -> io::Result<()> {
+        if length > self.max_frame_length {
+            return Err(invalid_data(format!(
+                "frame of {} bytes exceeds the maximum of {} bytes",
+                length, self.max_frame_length
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Encoder<Bytes> for HashFramedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, message: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        self.check_length(message.len())?;
+        let hash_bytes = DispnetHash::create(self.hash_type, &message, None).to_bytes();
+        dst.reserve(4 + message.len() + hash_bytes.len());
+        dst.put_u32(message.len() as u32);
+        dst.put_slice(&message);
+        dst.put_slice(&hash_bytes);
+        Ok(())
+    }
+}
+
+impl Decoder for HashFramedCodec {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<BytesMut>> {
+        if src.len() < 4 {
+            return Ok(None);
+        }
+        let message_length = u32::from_be_bytes(src[..4].try_into().unwrap()) as usize;
+        self.check_length(message_length)?;
+        // The hash header (type code and digest length) follows the message.
+        let hash_start = 4 + message_length;
+        if src.len() < hash_start + 3 {
+            src.reserve(hash_start + 3 - src.len());
+            return Ok(None);
+        }
+        let digest_length = u16::from_be_bytes([src[hash_start + 1], src[hash_start + 2]]) as usize;
+        let frame_length = hash_start + 3 + digest_length;
+        if src.len() < frame_length {
+            src.reserve(frame_length - src.len());
+            return Ok(None);
+        }
+        let hash = DispnetHash::from_bytes(&src[hash_start..frame_length])
+            .map_err(|error| invalid_data(format!("invalid frame hash: {:?}", error)))?;
+        if hash.hash_type != self.hash_type {
+            return Err(invalid_data(format!("frame hash type {:?} instead of {:?}", hash.hash_type, self.hash_type)));
+        }
+        src.advance(4);
+        let message = src.split_to(message_length);
+        src.advance(3 + digest_length);
+        if !DispnetHash::verify_instance(&hash, &message) {
+            return Err(invalid_data(format!("frame does not match its hash {}", hash)));
+        }
+        Ok(Some(message))
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use bytes::{Bytes, BytesMut};
+    use tokio_util::codec::{Decoder, Encoder};
+
+    use crate::{HashFramedCodec, HashType};
+
+    #[test]
+    fn encode_decode_frames() {
+        let mut codec = HashFramedCodec::new(HashType::Blake3);
+        let mut buffer = BytesMut::new();
+        codec.encode(Bytes::from_static(b"first"), &mut buffer).unwrap();
+        codec.encode(Bytes::new(), &mut buffer).unwrap();
+        codec.encode(Bytes::from_static(b"third"), &mut buffer).unwrap();
+        assert_eq!(buffer.len(), 3 * (4 + 35) + 10);
+
+        // Frames arrive in pieces.
+        let mut received = BytesMut::new();
+        let mut messages = Vec::new();
+        for byte in buffer.iter() {
+            received.extend_from_slice(&[*byte]);
+            if let Some(message) = codec.decode(&mut received).unwrap() {
+                messages.push(message);
+            }
+        }
+        assert_eq!(messages, vec![&b"first"[..], &b""[..], &b"third"[..]]);
+        assert!(received.is_empty());
+    }
+
+    #[test]
+    fn reject_invalid_frames() {
+        let mut codec = HashFramedCodec::new(HashType::Blake3).with_max_frame_length(16);
+        let mut buffer = BytesMut::new();
+        codec.encode(Bytes::from_static(b"message"), &mut buffer).unwrap();
+        buffer[5] ^= 0xff;
+        assert_eq!(codec.decode(&mut buffer).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut crc_buffer = BytesMut::new();
+        HashFramedCodec::new(HashType::CRC).encode(Bytes::from_static(b"message"), &mut crc_buffer).unwrap();
+        assert_eq!(codec.decode(&mut crc_buffer).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        assert_eq!(codec.encode(Bytes::from(vec![0; 17]), &mut BytesMut::new()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        let mut oversized = BytesMut::from(&17u32.to_be_bytes()[..]);
+        assert_eq!(codec.decode(&mut oversized).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(HashFramedCodec::new(HashType::Blake3).with_max_frame_length(usize::MAX).max_frame_length, u32::MAX as usize);
+    }
+}
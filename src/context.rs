@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use crate::{DispnetHash, HashConfig, HashType};
+
+/// Framing of the context for hash types without a native context: the context length
+/// (8 bytes little-endian) and the context, prepended to the value.
+/// Blake3 uses the context in its key derivation mode instead.
+pub(crate) fn context_prefix(context: &str) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(8 + context.len());
+    prefix.extend_from_slice(&(context.len() as u64).to_le_bytes());
+    prefix.extend_from_slice(context.as_bytes());
+    prefix
+}
+
+impl DispnetHash {
+    /// Verify a value hashed with a context (see [`HashConfig::context`]).
+    /// The context is not part of the hash, the verifier must use the same context as the creator.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashConfig, HashType};
+    ///
+    /// fn session_token() {
+    ///     let config = HashConfig::default().with_context("dispnet/session/v1");
+    ///     let token_hash = DispnetHash::create(HashType::Blake3, "token".as_bytes(), Some(config));
+    ///     assert!(DispnetHash::verify_in_context(&token_hash, "token".as_bytes(), "dispnet/session/v1"));
+    ///     assert!(!DispnetHash::verify_instance(&token_hash, "token".as_bytes()));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn verify_in_context(hash: &DispnetHash, value: &[u8], context: &str) -> bool {
+        if hash.hash_type == HashType::Argon2 {
+            let framed_value = [context_prefix(context).as_slice(), value].concat();
+            return DispnetHash::verify_instance(hash, &framed_value);
+        }
+        let config = HashConfig::default().with_context(context);
+        DispnetHash::create(hash.hash_type, value, Some(config)) == *hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, DispnetHasher, HashConfig, HashType};
+
+    fn context_config(context: &str) -> Option<HashConfig> {
+        Some(HashConfig::default().with_context(context))
+    }
+
+    #[test]
+    fn contexts_separate_digests() {
+        for hash_type in [
+            HashType::Blake3,
+            HashType::CRC,
+            HashType::Argon2,
+            HashType::SimHash,
+            HashType::GitSha1,
+        ] {
+            let plain_hash = DispnetHash::create(hash_type, "test".as_bytes(), None);
+            let blocks_hash =
+                DispnetHash::create(hash_type, "test".as_bytes(), context_config("blocks"));
+            let users_hash =
+                DispnetHash::create(hash_type, "test".as_bytes(), context_config("users"));
+            assert_ne!(blocks_hash, plain_hash);
+            assert_ne!(blocks_hash, users_hash);
+            assert!(DispnetHash::verify_in_context(
+                &blocks_hash,
+                "test".as_bytes(),
+                "blocks"
+            ));
+            assert!(!DispnetHash::verify_in_context(
+                &blocks_hash,
+                "test".as_bytes(),
+                "users"
+            ));
+        }
+        let blake3_hash = DispnetHash::create(
+            HashType::Blake3,
+            "test".as_bytes(),
+            context_config("blocks"),
+        );
+        assert_eq!(
+            blake3_hash.digest_value,
+            blake3::derive_key("blocks", "test".as_bytes())
+        );
+    }
+
+    #[test]
+    fn streaming_with_context() {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::SimHash] {
+            let mut hasher = DispnetHasher::new(hash_type, context_config("blocks"));
+            hasher.update(&data[..40_000]);
+            let mut resumed_hasher = DispnetHasher::import_state(&hasher.export_state()).unwrap();
+            resumed_hasher.update(&data[40_000..]);
+            assert_eq!(resumed_hasher.input_length(), data.len() as u64);
+            assert_eq!(
+                resumed_hasher.finalize(),
+                DispnetHash::create(hash_type, &data, context_config("blocks"))
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashMap, ops::Range};
+
+use crate::{DispnetHash, HashType, RollingHash};
+
+const CHUNK_WINDOW_SIZE: usize = 48;
+
+/// Split the data into content defined chunks, returns the byte ranges of the chunks.
+///
+/// A chunk ends where the [`RollingHash`] of the last 48 bytes matches the mask of
+/// `average_chunk_size` (rounded up to a power of two), chunks are between a quarter and four
+/// times the average size (except the last chunk). Inserting bytes only changes the chunks
+/// around the insertion, all other chunks keep their content and hash.
+/// # Usage
+/// ```
+/// use dispnet_hash::chunk_ranges;
+///
+/// fn chunk() {
+///     let data: Vec<u8> = (0..100_000u32).map(|i| (i * 7919 % 251) as u8).collect();
+///     let ranges = chunk_ranges(&data, 4096);
+///     assert_eq!(ranges.last().unwrap().end, data.len());
+/// }
+/// ```
+pub fn chunk_ranges(data: &[u8], average_chunk_size: usize) -> Vec<Range<usize>> {
+    let mask = (average_chunk_size.max(1).next_power_of_two() - 1) as u64;
+    let min_chunk_size = (average_chunk_size / 4).max(1);
+    let max_chunk_size = average_chunk_size.saturating_mul(4).max(1);
+    let mut ranges = Vec::new();
+    let mut rolling_hash = RollingHash::new(CHUNK_WINDOW_SIZE);
+    let mut start = 0;
+    for (index, byte) in data.iter().enumerate() {
+        let value = rolling_hash.update(*byte);
+        let length = index + 1 - start;
+        if length >= max_chunk_size || (length >= min_chunk_size && value & mask == 0) {
+            ranges.push(start..index + 1);
+            start = index + 1;
+            rolling_hash.reset();
+        }
+    }
+    if start < data.len() {
+        ranges.push(start..data.len());
+    }
+    ranges
+}
+
+/// Chunk of an input inserted into the [`DedupIndex`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkRef {
+    pub hash: DispnetHash,
+    /// Offset of the chunk in the input.
+    pub offset: u64,
+    pub length: usize,
+    /// True if the chunk was not in the index before and has to be stored.
+    pub is_new: bool,
+}
+
+/// Index entry of a stored chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DedupEntry {
+    pub hash: DispnetHash,
+    /// Number of inserted chunks which reference the stored chunk.
+    pub ref_count: u64,
+    /// Offset of the chunk in the store, new chunks are appended in insertion order.
+    pub offset: u64,
+    pub length: usize,
+}
+
+/// Deduplication index of a content addressed chunk store.
+///
+/// Inputs are split with [`chunk_ranges`] and every chunk is hashed, chunks which are already
+/// in the index only increase the reference count.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DedupIndex, HashType};
+///
+/// fn store(blobs: &[&[u8]], store: &mut Vec<u8>) {
+///     let mut index = DedupIndex::new(HashType::Blake3, 8192);
+///     for blob in blobs {
+///         for chunk in index.insert(blob).iter().filter(|chunk| chunk.is_new) {
+///             let start = chunk.offset as usize;
+///             store.extend_from_slice(&blob[start..start + chunk.length]);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DedupIndex {
+    hash_type: HashType,
+    average_chunk_size: usize,
+    entries: HashMap<Vec<u8>, DedupEntry>,
+    stored_length: u64,
+}
+
+impl DedupIndex {
+    pub fn new(hash_type: HashType, average_chunk_size: usize) -> Self {
+        Self {
+            hash_type,
+            average_chunk_size,
+            entries: HashMap::new(),
+            stored_length: 0,
+        }
+    }
+
+    /// Chunk and hash the input, add the chunks to the index and return them in input order.
+    pub fn insert(&mut self, data: &[u8]) -> Vec<ChunkRef> {
+        chunk_ranges(data, self.average_chunk_size)
+            .into_iter()
+            .map(|range| {
+                let hash = DispnetHash::create(self.hash_type, &data[range.clone()], None);
+                let length = range.len();
+                let mut is_new = false;
+                let entry = self.entries.entry(hash.to_bytes()).or_insert_with(|| {
+                    is_new = true;
+                    let offset = self.stored_length;
+                    self.stored_length += length as u64;
+                    DedupEntry {
+                        hash: hash.clone(),
+                        ref_count: 0,
+                        offset,
+                        length,
+                    }
+                });
+                entry.ref_count += 1;
+                ChunkRef {
+                    hash,
+                    offset: range.start as u64,
+                    length,
+                    is_new,
+                }
+            })
+            .collect()
+    }
+
+    /// Drop one reference of the chunk and return the remaining references.
+    /// The entry is removed when no reference is left, `None` if the chunk is not in the index.
+    pub fn release(&mut self, hash: &DispnetHash) -> Option<u64> {
+        let key = hash.to_bytes();
+        let entry = self.entries.get_mut(&key)?;
+        entry.ref_count -= 1;
+        let ref_count = entry.ref_count;
+        if ref_count == 0 {
+            self.entries.remove(&key);
+        }
+        Some(ref_count)
+    }
+
+    pub fn get(&self, hash: &DispnetHash) -> Option<&DedupEntry> {
+        self.entries.get(&hash.to_bytes())
+    }
+
+    /// Total length of all chunks which were new when they were inserted.
+    pub fn stored_length(&self) -> u64 {
+        self.stored_length
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{chunk_ranges, DedupIndex, HashType};
+
+    fn content(length: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed;
+        (0..length).map(|_| (crate::splitmix64(&mut state) >> 56) as u8).collect()
+    }
+
+    #[test]
+    fn content_defined_chunks() {
+        let data = content(200_000, 1);
+        let ranges = chunk_ranges(&data, 4096);
+        assert_eq!(ranges.first().unwrap().start, 0);
+        assert_eq!(ranges.last().unwrap().end, data.len());
+        assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(ranges[..ranges.len() - 1].iter().all(|range| range.len() >= 1024 && range.len() <= 16384));
+        assert!(chunk_ranges(&[], 4096).is_empty());
+    }
+
+    #[test]
+    fn dedup_index() {
+        let data = content(200_000, 2);
+        let mut edited = data.clone();
+        edited.splice(100_000..100_000, b"inserted".iter().copied());
+
+        let mut index = DedupIndex::new(HashType::Blake3, 4096);
+        let chunks = index.insert(&data);
+        assert!(chunks.iter().all(|chunk| chunk.is_new));
+        assert_eq!(index.stored_length(), data.len() as u64);
+
+        let edited_chunks = index.insert(&edited);
+        let new_length: usize = edited_chunks.iter().filter(|chunk| chunk.is_new).map(|chunk| chunk.length).sum();
+        assert!(new_length < 3 * 16384);
+        let shared_chunk = &edited_chunks[0];
+        assert!(!shared_chunk.is_new);
+        assert_eq!(index.get(&shared_chunk.hash).unwrap().ref_count, 2);
+        assert_eq!(index.release(&shared_chunk.hash), Some(1));
+        assert_eq!(index.release(&shared_chunk.hash), Some(0));
+        assert_eq!(index.get(&shared_chunk.hash), None);
+        assert_eq!(index.release(&shared_chunk.hash), None);
+    }
+}
@@ -0,0 +1,127 @@
+use std::str::from_utf8;
+
+use crate::{argon2_backend, digest_fits_type, DispnetHash, HashConfig, HashError, HashType};
+
+/// Computes the digests of the hash types, implement it to replace the built-in algorithms
+/// (e.g. with hardware accelerated or HSM-backed implementations).
+///
+/// The digest must be in the dispnet digest format of the hash type (see [`DispnetHash::digest_value`]).
+/// Implementations delegate the hash types they don't handle to [`DefaultEngine`].
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DefaultEngine, DispnetHash, HashConfig, HashEngine, HashType};
+///
+/// struct AcceleratedBlake3;
+///
+/// impl HashEngine for AcceleratedBlake3 {
+///     fn digest_into(&self, hash_type: HashType, value: &[u8], config: Option<&HashConfig>, digest_value: &mut Vec<u8>) {
+///         match hash_type {
+///             HashType::Blake3 => digest_value.extend_from_slice(blake3::hash(value).as_bytes()),
+///             _ => DefaultEngine.digest_into(hash_type, value, config, digest_value),
+///         }
+///     }
+/// }
+///
+/// fn create_hash() {
+///     let dispnet_hash = DispnetHash::create_with_engine(&AcceleratedBlake3, HashType::Blake3, "test".as_bytes(), None).unwrap();
+///     assert_eq!(dispnet_hash, DispnetHash::new("test".as_bytes()));
+/// }
+/// ```
+pub trait HashEngine {
+    /// Append the digest of the value to the buffer (see [`HashConfig`] for the options of the hash types).
+    fn digest_into(&self, hash_type: HashType, value: &[u8], config: Option<&HashConfig>, digest_value: &mut Vec<u8>);
+
+    /// Verify the value against the hash. Argon2 is verified with the parameters and salt
+    /// of its PHC string, all other types by computing the digest again.
+    fn verify(&self, hash: &DispnetHash, value: &[u8]) -> bool {
+        if hash.hash_type == HashType::Argon2 {
+            return from_utf8(&hash.digest_value).is_ok_and(|phc| argon2_backend::verify_encoded(phc, value));
+        }
+        let mut digest_value = Vec::with_capacity(hash.digest_value.len());
+        self.digest_into(hash.hash_type, value, None, &mut digest_value);
+        digest_value == hash.digest_value
+    }
+}
+
+/// Built-in algorithms of the crate, used by [`DispnetHash::create`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultEngine;
+
+impl HashEngine for DefaultEngine {
+    fn digest_into(&self, hash_type: HashType, value: &[u8], config: Option<&HashConfig>, digest_value: &mut Vec<u8>) {
+        DispnetHash::digest_into(hash_type, value, config, digest_value);
+    }
+}
+
+impl DispnetHash {
+    /// Create a hash (see [`DispnetHash::create`]) with the digest computed by the engine.
+    /// Fails if the engine returns a digest which does not fit the hash type.
+    pub fn create_with_engine(
+        engine: &dyn HashEngine,
+        hash_type: HashType,
+        value: &[u8],
+        config: Option<HashConfig>,
+    ) -> Result<Self, HashError> {
+        let hash_type = hash_type.configured(config.as_ref());
+        let mut digest_value = Vec::new();
+        engine.digest_into(hash_type, value, config.as_ref(), &mut digest_value);
+        if !digest_fits_type(hash_type, digest_value.iter().copied()) {
+            return Err(HashError::InvalidDigestForType {
+                hash_type,
+                length: digest_value.len(),
+            });
+        }
+        Ok(DispnetHash::from_digest(hash_type, digest_value))
+    }
+
+    /// Verify a hash with the engine (see [`DispnetHash::verify_instance`]).
+    #[must_use]
+    pub fn verify_with_engine(engine: &dyn HashEngine, hash: &DispnetHash, value: &[u8]) -> bool {
+        engine.verify(hash, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultEngine, DispnetHash, HashConfig, HashEngine, HashError, HashType};
+
+    /// Engine with a constant Blake3 digest and a SimHash digest of the wrong length.
+    struct ZeroBlake3;
+
+    impl HashEngine for ZeroBlake3 {
+        fn digest_into(&self, hash_type: HashType, value: &[u8], config: Option<&HashConfig>, digest_value: &mut Vec<u8>) {
+            match hash_type {
+                HashType::Blake3 => digest_value.extend_from_slice(&[0; 32]),
+                HashType::SimHash => digest_value.extend_from_slice(&[0; 3]),
+                _ => DefaultEngine.digest_into(hash_type, value, config, digest_value),
+            }
+        }
+    }
+
+    #[test]
+    fn default_engine_matches_create() {
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::CrcIeee, HashType::SimHash, HashType::GitSha1] {
+            let engine_hash = DispnetHash::create_with_engine(&DefaultEngine, hash_type, "test".as_bytes(), None).unwrap();
+            assert_eq!(engine_hash, DispnetHash::create(hash_type, "test".as_bytes(), None));
+            assert!(DispnetHash::verify_with_engine(&DefaultEngine, &engine_hash, "test".as_bytes()));
+        }
+        let argon2_hash = DispnetHash::create_with_engine(&DefaultEngine, HashType::Argon2, "test".as_bytes(), None).unwrap();
+        assert!(DispnetHash::verify_with_engine(&DefaultEngine, &argon2_hash, "test".as_bytes()));
+        assert!(!DispnetHash::verify_with_engine(&DefaultEngine, &argon2_hash, "other".as_bytes()));
+    }
+
+    #[test]
+    fn custom_engine() {
+        let blake3_hash = DispnetHash::create_with_engine(&ZeroBlake3, HashType::Blake3, "test".as_bytes(), None).unwrap();
+        assert_eq!(blake3_hash.digest_value, vec![0; 32]);
+        assert!(DispnetHash::verify_with_engine(&ZeroBlake3, &blake3_hash, "other".as_bytes()));
+        assert!(!DispnetHash::verify_with_engine(&DefaultEngine, &blake3_hash, "other".as_bytes()));
+        let crc_hash = DispnetHash::create_with_engine(&ZeroBlake3, HashType::CRC, "test".as_bytes(), None).unwrap();
+        assert_eq!(crc_hash, DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+        assert!(matches!(
+            DispnetHash::create_with_engine(&ZeroBlake3, HashType::SimHash, "test".as_bytes(), None),
+            Err(HashError::InvalidDigestForType { hash_type: HashType::SimHash, length: 3 })
+        ));
+    }
+}
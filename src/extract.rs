@@ -0,0 +1,185 @@
+//! Web framework extractors which parse a [`DispnetHash`] from the request URL.
+//!
+//! Invalid hashes are rejected with `400 Bad Request` and the [`HashError`] as detail.
+use std::fmt;
+
+use crate::{DispnetHash, HashError};
+
+/// Extract a hash from the (single) path parameter, e.g. a route `/blobs/{hash}`.
+///
+/// # Usage
+/// ```ignore
+/// async fn get_blob(HashPath(hash): HashPath) -> String {
+///     hash.to_string()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashPath(pub DispnetHash);
+
+/// Extract a hash from the query parameter `hash`, e.g. `/blobs?hash=0100...`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashQuery(pub DispnetHash);
+
+/// Rejection of [`HashPath`] and [`HashQuery`].
+#[derive(Debug)]
+pub enum HashRejection {
+    /// The request has no (or more than one) hash parameter.
+    MissingHash,
+    InvalidHash(HashError),
+}
+
+impl fmt::Display for HashRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HashRejection::MissingHash => write!(f, "Missing dispnet hash parameter"),
+            HashRejection::InvalidHash(error) => write!(f, "Invalid dispnet hash: {:?}", error),
+        }
+    }
+}
+
+fn parse_hash(raw_hash: Option<&str>) -> Result<DispnetHash, HashRejection> {
+    let raw_hash = raw_hash.ok_or(HashRejection::MissingHash)?;
+    raw_hash.parse::<DispnetHash>().map_err(HashRejection::InvalidHash)
+}
+
+/// Value of the `hash` parameter in a query string (without percent decoding, hashes are hex).
+fn query_hash(query: Option<&str>) -> Option<&str> {
+    query?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("hash="))
+}
+
+#[cfg(feature = "axum")]
+mod axum_extract {
+    use axum::{
+        extract::{FromRequestParts, Path},
+        http::{request::Parts, StatusCode},
+        response::{IntoResponse, Response},
+    };
+
+    use super::{parse_hash, query_hash, HashPath, HashQuery, HashRejection};
+
+    impl IntoResponse for HashRejection {
+        fn into_response(self) -> Response {
+            (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+        }
+    }
+
+    impl<S: Send + Sync> FromRequestParts<S> for HashPath {
+        type Rejection = HashRejection;
+
+        async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+            let raw_hash = Path::<String>::from_request_parts(parts, state).await.ok().map(|Path(hash)| hash);
+            parse_hash(raw_hash.as_deref()).map(HashPath)
+        }
+    }
+
+    impl<S: Send + Sync> FromRequestParts<S> for HashQuery {
+        type Rejection = HashRejection;
+
+        async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+            parse_hash(query_hash(parts.uri.query())).map(HashQuery)
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_extract {
+    use std::future::{ready, Ready};
+
+    use actix_web::{dev::Payload, http::StatusCode, FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+    use super::{parse_hash, query_hash, HashPath, HashQuery, HashRejection};
+
+    impl ResponseError for HashRejection {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::BAD_REQUEST
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            HttpResponse::BadRequest().body(self.to_string())
+        }
+    }
+
+    impl FromRequest for HashPath {
+        type Error = HashRejection;
+        type Future = Ready<Result<Self, HashRejection>>;
+
+        fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+            let match_info = request.match_info();
+            let raw_hash = match match_info.iter().count() {
+                1 => match_info.iter().next().map(|(_, value)| value),
+                _ => None,
+            };
+            ready(parse_hash(raw_hash).map(HashPath))
+        }
+    }
+
+    impl FromRequest for HashQuery {
+        type Error = HashRejection;
+        type Future = Ready<Result<Self, HashRejection>>;
+
+        fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+            ready(parse_hash(query_hash(Some(request.query_string()))).map(HashQuery))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hash, query_hash, HashRejection};
+
+    #[test]
+    fn query_parameter() {
+        assert_eq!(query_hash(Some("a=1&hash=02001032323538363632303830")), Some("02001032323538363632303830"));
+        assert_eq!(query_hash(Some("a=1")), None);
+        assert!(matches!(parse_hash(None), Err(HashRejection::MissingHash)));
+        assert!(matches!(parse_hash(Some("01")), Err(HashRejection::InvalidHash(_))));
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn axum_extractors() {
+        use axum::{body::Body, http::{Request, StatusCode}, routing::get, Router};
+        use tower::ServiceExt;
+
+        use crate::{HashPath, HashQuery};
+
+        let app = Router::new()
+            .route("/blobs/{hash}", get(|HashPath(hash): HashPath| async move { hash.to_string() }))
+            .route("/blobs", get(|HashQuery(hash): HashQuery| async move { hash.to_string() }));
+        for (uri, status) in [
+            ("/blobs/02001032323538363632303830", StatusCode::OK),
+            ("/blobs/0200103232", StatusCode::BAD_REQUEST),
+            ("/blobs?hash=02001032323538363632303830", StatusCode::OK),
+            ("/blobs", StatusCode::BAD_REQUEST),
+        ] {
+            let response = app.clone().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+            assert_eq!(response.status(), status, "{}", uri);
+        }
+    }
+
+    #[cfg(feature = "actix-web")]
+    #[actix_web::test]
+    async fn actix_extractors() {
+        use actix_web::{http::StatusCode, test, web, App};
+
+        use crate::{HashPath, HashQuery};
+
+        let app = test::init_service(
+            App::new()
+                .route("/blobs/{hash}", web::get().to(|HashPath(hash): HashPath| async move { hash.to_string() }))
+                .route("/blobs", web::get().to(|HashQuery(hash): HashQuery| async move { hash.to_string() })),
+        )
+        .await;
+        for (uri, status) in [
+            ("/blobs/02001032323538363632303830", StatusCode::OK),
+            ("/blobs/0200103232", StatusCode::BAD_REQUEST),
+            ("/blobs?hash=02001032323538363632303830", StatusCode::OK),
+            ("/blobs", StatusCode::BAD_REQUEST),
+        ] {
+            let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+            assert_eq!(response.status(), status, "{}", uri);
+        }
+    }
+}
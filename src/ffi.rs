@@ -0,0 +1,119 @@
+//! UniFFI bindings (Kotlin/Swift) for creating, parsing and verifying hashes.
+use std::fmt;
+
+use crate::{DispnetHash, HashConfig, HashType};
+
+/// Hash type of the bindings (mirrors [`HashType`]).
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum FfiHashType {
+    Blake3,
+    Crc,
+    Argon2,
+    SimHash,
+    GitSha1,
+    CrcIeee,
+    CrcKoopman,
+}
+
+impl From<FfiHashType> for HashType {
+    fn from(hash_type: FfiHashType) -> Self {
+        match hash_type {
+            FfiHashType::Blake3 => HashType::Blake3,
+            FfiHashType::Crc => HashType::CRC,
+            FfiHashType::Argon2 => HashType::Argon2,
+            FfiHashType::SimHash => HashType::SimHash,
+            FfiHashType::GitSha1 => HashType::GitSha1,
+            FfiHashType::CrcIeee => HashType::CrcIeee,
+            FfiHashType::CrcKoopman => HashType::CrcKoopman,
+        }
+    }
+}
+
+impl From<HashType> for FfiHashType {
+    fn from(hash_type: HashType) -> Self {
+        match hash_type {
+            HashType::Blake3 => FfiHashType::Blake3,
+            HashType::CRC => FfiHashType::Crc,
+            HashType::Argon2 => FfiHashType::Argon2,
+            HashType::SimHash => FfiHashType::SimHash,
+            HashType::GitSha1 => FfiHashType::GitSha1,
+            HashType::CrcIeee => FfiHashType::CrcIeee,
+            HashType::CrcKoopman => FfiHashType::CrcKoopman,
+        }
+    }
+}
+
+/// Parts of a dispnet hash and its display format.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct FfiDispnetHash {
+    pub hash_type: FfiHashType,
+    pub digest_length: u32,
+    pub digest_value: Vec<u8>,
+    pub display: String,
+}
+
+impl From<DispnetHash> for FfiDispnetHash {
+    fn from(hash: DispnetHash) -> Self {
+        Self {
+            hash_type: hash.hash_type.into(),
+            digest_length: hash.digest_length as u32,
+            display: hash.to_string(),
+            digest_value: hash.digest_value,
+        }
+    }
+}
+
+#[derive(Debug, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum FfiHashError {
+    InvalidHash { message: String },
+}
+
+impl fmt::Display for FfiHashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FfiHashError::InvalidHash { message } => write!(f, "Invalid dispnet hash: {}", message),
+        }
+    }
+}
+
+/// Create a hash (see [`DispnetHash::create`]), the salt is only used by Argon2.
+#[uniffi::export]
+pub fn dispnet_create(hash_type: FfiHashType, value: Vec<u8>, salt: Option<Vec<u8>>) -> FfiDispnetHash {
+    let config = salt.map(|salt| HashConfig::default().with_salt(salt));
+    DispnetHash::create(hash_type.into(), &value, config).into()
+}
+
+/// Parse the display format of a hash.
+#[uniffi::export]
+pub fn dispnet_parse(hash: String) -> Result<FfiDispnetHash, FfiHashError> {
+    hash.parse::<DispnetHash>()
+        .map(FfiDispnetHash::from)
+        .map_err(|error| FfiHashError::InvalidHash {
+            message: format!("{:?}", error),
+        })
+}
+
+/// Verify the display format of a hash with the raw value (see [`DispnetHash::verify`]).
+#[uniffi::export]
+pub fn dispnet_verify(hash: String, value: Vec<u8>) -> bool {
+    DispnetHash::verify(&hash, &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dispnet_create, dispnet_parse, dispnet_verify, FfiHashError, FfiHashType};
+
+    #[test]
+    fn ffi_api() {
+        let hash = dispnet_create(FfiHashType::Crc, b"test".to_vec(), None);
+        assert_eq!(hash.display, "02001032323538363632303830");
+        assert_eq!(dispnet_parse(hash.display.clone()).unwrap(), hash);
+        assert!(dispnet_verify(hash.display, b"test".to_vec()));
+        assert!(matches!(dispnet_parse("0100".to_owned()), Err(FfiHashError::InvalidHash { .. })));
+
+        let salt_hash = dispnet_create(FfiHashType::Argon2, b"test".to_vec(), Some(b"12345678".to_vec()));
+        assert_eq!(salt_hash.hash_type, FfiHashType::Argon2);
+        assert!(dispnet_verify(salt_hash.display, b"test".to_vec()));
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    fs::{self, File},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{DispnetHash, DispnetHasher, HashType};
+
+pub(crate) const READ_BUFFER_LEN: usize = 64 * 1024;
+const CHECKPOINT_INTERVAL: u64 = 64 * 1024 * 1024;
+const FILE_METADATA_MAGIC: &[u8] = b"DFM1";
+
+/// File metadata which is included in the hash by [`hash_file_with`].
+///
+/// # Encoding
+///
+/// If any option is enabled the metadata is hashed before the content as:
+///
+/// * `DFM1` as magic bytes.
+/// * `0x01` + size as u64 (big-endian) if `size` is enabled.
+/// * `0x02` + permission mode bits as u32 (big-endian) if `permissions` is enabled
+///   (on non unix platforms 0o444 for read-only files and 0o644 otherwise).
+/// * `0x03` + modification time as seconds i64 and nanoseconds u32 since the unix epoch (big-endian) if `mtime` is enabled.
+/// * `0x04` + length as u32 (big-endian) + target path bytes if `symlink_target` is enabled and the path is a symlink.
+/// * `0x00` as end marker.
+///
+/// Without any enabled option the hash is equal to [`hash_file`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FileHashOptions {
+    pub size: bool,
+    pub permissions: bool,
+    pub mtime: bool,
+    /// Hash the target of a symlink instead of following it (the content of the target is not hashed).
+    pub symlink_target: bool,
+}
+
+/// Hash the content of a file.
+///
+/// With the `io-uring` feature on Linux the file is read with io_uring (several reads in flight while hashing),
+/// if io_uring is not available the file is read sequentially.
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_file, HashType};
+///
+/// fn hash_cargo_toml() {
+///     let dispnet_hash = hash_file("Cargo.toml", HashType::Blake3).unwrap();
+/// }
+/// ```
+pub fn hash_file(path: impl AsRef<Path>, hash_type: HashType) -> io::Result<DispnetHash> {
+    let mut file = File::open(path)?;
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    if let Some(result) = crate::uring::hash_file(&file, &mut hasher) {
+        result?;
+        return Ok(hasher.finalize());
+    }
+    hash_reader(&mut file, &mut hasher, |_| Ok(()))?;
+    Ok(hasher.finalize())
+}
+
+/// Hash the content of a file together with the selected metadata (see [`FileHashOptions`]).
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_file_with, FileHashOptions, HashType};
+///
+/// fn hash_cargo_toml_with_permissions() {
+///     let options = FileHashOptions { permissions: true, ..Default::default() };
+///     let dispnet_hash = hash_file_with("Cargo.toml", HashType::Blake3, &options).unwrap();
+/// }
+/// ```
+pub fn hash_file_with(path: impl AsRef<Path>, hash_type: HashType, options: &FileHashOptions) -> io::Result<DispnetHash> {
+    let path = path.as_ref();
+    if *options == FileHashOptions::default() {
+        return hash_file(path, hash_type);
+    }
+    let metadata = if options.symlink_target {
+        fs::symlink_metadata(path)?
+    } else {
+        fs::metadata(path)?
+    };
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    hasher.update(FILE_METADATA_MAGIC);
+    if options.size {
+        hasher.update(&[0x01]);
+        hasher.update(&metadata.len().to_be_bytes());
+    }
+    if options.permissions {
+        hasher.update(&[0x02]);
+        hasher.update(&permission_mode(&metadata).to_be_bytes());
+    }
+    if options.mtime {
+        let (seconds, nanos) = unix_time(metadata.modified()?);
+        hasher.update(&[0x03]);
+        hasher.update(&seconds.to_be_bytes());
+        hasher.update(&nanos.to_be_bytes());
+    }
+    let is_symlink = options.symlink_target && metadata.file_type().is_symlink();
+    if is_symlink {
+        let target = path_bytes(&fs::read_link(path)?);
+        hasher.update(&[0x04]);
+        hasher.update(&(target.len() as u32).to_be_bytes());
+        hasher.update(&target);
+    }
+    hasher.update(&[0x00]);
+    if !is_symlink {
+        hash_reader(&mut File::open(path)?, &mut hasher, |_| Ok(()))?;
+    }
+    Ok(hasher.finalize())
+}
+
+#[cfg(unix)]
+fn permission_mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+fn permission_mode(metadata: &fs::Metadata) -> u32 {
+    if metadata.permissions().readonly() {
+        0o444
+    } else {
+        0o644
+    }
+}
+
+fn unix_time(time: SystemTime) -> (i64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(error) => {
+            let duration = error.duration();
+            if duration.subsec_nanos() == 0 {
+                (-(duration.as_secs() as i64), 0)
+            } else {
+                (-(duration.as_secs() as i64) - 1, 1_000_000_000 - duration.subsec_nanos())
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(not(unix))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().as_bytes().to_vec()
+}
+
+/// Hash the content of a file and persist the progress every 64 MiB into the checkpoint file.
+///
+/// If the checkpoint file exists (e.g. after a crash) hashing continues where the checkpoint left off.
+/// A checkpoint is ignored if it was written for another hash type or if the size or modification time of the file changed.
+/// The checkpoint file is removed after the hash is finished.
+///
+/// Only Blake3 and the CRC types are supported (`InvalidInput` error otherwise), the other types buffer
+/// the whole file and every checkpoint would contain all data read so far.
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_file_resumable, HashType};
+///
+/// fn hash_large_file() {
+///     let dispnet_hash = hash_file_resumable("disk.img", "disk.img.checkpoint", HashType::Blake3).unwrap();
+/// }
+/// ```
+pub fn hash_file_resumable(
+    path: impl AsRef<Path>,
+    checkpoint_file: impl AsRef<Path>,
+    hash_type: HashType,
+) -> io::Result<DispnetHash> {
+    let checkpoint_file = checkpoint_file.as_ref();
+    let new_hasher = DispnetHasher::new(hash_type, None);
+    if !new_hasher.is_streaming() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} hashes can not be resumed", hash_type),
+        ));
+    }
+    let mut file = File::open(path)?;
+    let file_stamp = FileStamp::from_file(&file)?;
+    let mut hasher = read_checkpoint(checkpoint_file, &file_stamp, hash_type).unwrap_or(new_hasher);
+    file.seek(SeekFrom::Start(hasher.input_length()))?;
+
+    let mut next_checkpoint = hasher.input_length() + CHECKPOINT_INTERVAL;
+    hash_reader(&mut file, &mut hasher, |hasher| {
+        if hasher.input_length() >= next_checkpoint {
+            write_checkpoint(checkpoint_file, &file_stamp, hasher)?;
+            next_checkpoint = hasher.input_length() + CHECKPOINT_INTERVAL;
+        }
+        Ok(())
+    })?;
+
+    let dispnet_hash = hasher.finalize();
+    match fs::remove_file(checkpoint_file) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(dispnet_hash),
+    }
+}
+
+pub(crate) fn hash_reader(
+    reader: &mut impl Read,
+    hasher: &mut DispnetHasher,
+    mut on_progress: impl FnMut(&DispnetHasher) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut buffer = vec![0; READ_BUFFER_LEN];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        hasher.update(&buffer[..read]);
+        on_progress(hasher)?;
+    }
+}
+
+/// Size and modification time of a file to detect changes between checkpoints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FileStamp {
+    length: u64,
+    modified_nanos: u128,
+}
+
+impl FileStamp {
+    pub(crate) fn from_file(file: &File) -> io::Result<Self> {
+        let metadata = file.metadata()?;
+        let modified_nanos = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        Ok(Self {
+            length: metadata.len(),
+            modified_nanos,
+        })
+    }
+}
+
+fn read_checkpoint(checkpoint_file: &Path, file_stamp: &FileStamp, hash_type: HashType) -> Option<DispnetHasher> {
+    let checkpoint = fs::read(checkpoint_file).ok()?;
+    if checkpoint.len() < 24 {
+        return None;
+    }
+    let (raw_stamp, state) = checkpoint.split_at(24);
+    let checkpoint_stamp = FileStamp {
+        length: u64::from_be_bytes(raw_stamp[..8].try_into().unwrap()),
+        modified_nanos: u128::from_be_bytes(raw_stamp[8..].try_into().unwrap()),
+    };
+    let hasher = DispnetHasher::import_state(state).ok()?;
+    if checkpoint_stamp != *file_stamp
+        || *hasher.hash_type() != hash_type
+        || hasher.input_length() > file_stamp.length
+    {
+        return None;
+    }
+    Some(hasher)
+}
+
+fn write_checkpoint(checkpoint_file: &Path, file_stamp: &FileStamp, hasher: &DispnetHasher) -> io::Result<()> {
+    let mut checkpoint = Vec::new();
+    checkpoint.extend_from_slice(&file_stamp.length.to_be_bytes());
+    checkpoint.extend_from_slice(&file_stamp.modified_nanos.to_be_bytes());
+    checkpoint.extend_from_slice(&hasher.export_state());
+
+    // Write the checkpoint next to the target and rename it, so a crash never leaves a torn checkpoint.
+    // The content is synced before the rename and the directory after it, otherwise the rename could
+    // be persisted before the content.
+    let mut temp_file = PathBuf::from(checkpoint_file);
+    temp_file.as_mut_os_string().push(".tmp");
+    let mut file = File::create(&temp_file)?;
+    file.write_all(&checkpoint)?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&temp_file, checkpoint_file)?;
+    // Directories can't be opened with `File::open` on Windows.
+    #[cfg(unix)]
+    {
+        let directory = checkpoint_file.parent().filter(|parent| !parent.as_os_str().is_empty());
+        File::open(directory.unwrap_or(Path::new(".")))?.sync_all()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, fs::File, path::PathBuf};
+
+    use super::{write_checkpoint, FileStamp};
+    use crate::{hash_file, hash_file_resumable, hash_file_with, DispnetHash, DispnetHasher, FileHashOptions, HashType};
+
+    fn test_file(name: &str, length: usize) -> (PathBuf, Vec<u8>) {
+        let path = std::env::temp_dir().join(format!("dispnet-hash-{}-{}", std::process::id(), name));
+        let data: Vec<u8> = (0..length).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &data).unwrap();
+        (path, data)
+    }
+
+    #[test]
+    fn hash_file_content() {
+        let (path, data) = test_file("hash_file", 150_000);
+        assert_eq!(hash_file(&path, HashType::Blake3).unwrap(), DispnetHash::new(&data));
+        assert_eq!(hash_file(&path, HashType::CRC).unwrap(), DispnetHash::create(HashType::CRC, &data, None));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn hash_file_metadata() {
+        let (path, data) = test_file("metadata", 1000);
+        assert_eq!(hash_file_with(&path, HashType::Blake3, &FileHashOptions::default()).unwrap(), DispnetHash::new(&data));
+        let size_options = FileHashOptions { size: true, ..Default::default() };
+        let expected = [&b"DFM1\x01"[..], &1000u64.to_be_bytes(), &[0x00], &data].concat();
+        assert_eq!(hash_file_with(&path, HashType::Blake3, &size_options).unwrap(), DispnetHash::new(&expected));
+        let all_options = FileHashOptions { size: true, permissions: true, mtime: true, symlink_target: true };
+        assert_ne!(hash_file_with(&path, HashType::Blake3, &all_options).unwrap(), hash_file_with(&path, HashType::Blake3, &size_options).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hash_file_symlink_target() {
+        let (path, data) = test_file("symlink-target", 100);
+        let link = path.with_extension("link");
+        std::os::unix::fs::symlink(&path, &link).unwrap();
+        let options = FileHashOptions { symlink_target: true, ..Default::default() };
+        let target = path.to_str().unwrap().as_bytes();
+        let expected = [&b"DFM1\x04"[..], &(target.len() as u32).to_be_bytes(), target, &[0x00]].concat();
+        assert_eq!(hash_file_with(&link, HashType::Blake3, &options).unwrap(), DispnetHash::new(&expected));
+        assert_eq!(hash_file(&link, HashType::Blake3).unwrap(), DispnetHash::new(&data));
+        fs::remove_file(&link).unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn hash_file_resume_checkpoint() {
+        let (path, data) = test_file("resumable", 150_000);
+        let checkpoint_file = path.with_extension("checkpoint");
+        let mut hasher = DispnetHasher::new(HashType::Blake3, None);
+        hasher.update(&data[..70_000]);
+        let file_stamp = FileStamp::from_file(&File::open(&path).unwrap()).unwrap();
+        write_checkpoint(&checkpoint_file, &file_stamp, &hasher).unwrap();
+
+        assert_eq!(hash_file_resumable(&path, &checkpoint_file, HashType::Blake3).unwrap(), DispnetHash::new(&data));
+        assert!(!checkpoint_file.exists());
+
+        fs::write(&checkpoint_file, b"corrupt").unwrap();
+        assert_eq!(hash_file_resumable(&path, &checkpoint_file, HashType::CRC).unwrap(), DispnetHash::create(HashType::CRC, &data, None));
+        let error = hash_file_resumable(&path, &checkpoint_file, HashType::SimHash).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        fs::remove_file(&path).unwrap();
+    }
+}
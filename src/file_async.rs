@@ -0,0 +1,46 @@
+use std::{io, path::Path};
+
+use tokio::{fs::File, io::AsyncReadExt};
+
+use crate::{file::READ_BUFFER_LEN, DispnetHash, DispnetHasher, HashType};
+
+/// Hash the content of a file with `tokio::fs` (feature `tokio`), equal to [`crate::hash_file`].
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_file_async, HashType};
+///
+/// async fn hash_upload() {
+///     let dispnet_hash = hash_file_async("Cargo.toml", HashType::Blake3).await.unwrap();
+/// }
+/// ```
+pub async fn hash_file_async(path: impl AsRef<Path>, hash_type: HashType) -> io::Result<DispnetHash> {
+    let mut file = File::open(path).await?;
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    let mut buffer = vec![0; READ_BUFFER_LEN];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok(hasher.finalize());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{hash_file, hash_file_async, HashType};
+
+    #[tokio::test]
+    async fn hash_file_async_matches_hash_file() {
+        let path = std::env::temp_dir().join(format!("dispnet-hash-{}-async", std::process::id()));
+        let content: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &content).unwrap();
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::GitSha1] {
+            assert_eq!(hash_file_async(&path, hash_type).await.unwrap(), hash_file(&path, hash_type).unwrap());
+        }
+        fs::remove_file(&path).unwrap();
+        assert!(hash_file_async(&path, HashType::Blake3).await.is_err());
+    }
+}
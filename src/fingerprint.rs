@@ -0,0 +1,230 @@
+use crate::{DispnetHash, HashError, HashType};
+
+/// PGP word list for bytes at even positions (two syllables).
+const EVEN_WORDS: [&str; 256] = [
+    "aardvark", "absurd", "accrue", "acme", "adrift", "adult", "afflict", "ahead", "aimless", "Algol",
+    "allow", "alone", "ammo", "ancient", "apple", "artist", "assume", "Athens", "atlas", "Aztec", "baboon",
+    "backfield", "backward", "banjo", "beaming", "bedlamp", "beehive", "beeswax", "befriend", "Belfast",
+    "berserk", "billiard", "bison", "blackjack", "blockade", "blowtorch", "bluebird", "bombast", "bookshelf",
+    "brackish", "breadline", "breakup", "brickyard", "briefcase", "Burbank", "button", "buzzard", "cement",
+    "chairlift", "chatter", "checkup", "chisel", "choking", "chopper", "Christmas", "clamshell", "classic",
+    "classroom", "cleanup", "clockwork", "cobra", "commence", "concert", "cowbell", "crackdown", "cranky",
+    "crowfoot", "crucial", "crumpled", "crusade", "cubic", "dashboard", "deadbolt", "deckhand", "dogsled",
+    "dragnet", "drainage", "dreadful", "drifter", "dropper", "drumbeat", "drunken", "Dupont", "dwelling",
+    "eating", "edict", "egghead", "eightball", "endorse", "endow", "enlist", "erase", "escape", "exceed",
+    "eyeglass", "eyetooth", "facial", "fallout", "flagpole", "flatfoot", "flytrap", "fracture", "framework",
+    "freedom", "frighten", "gazelle", "Geiger", "glitter", "glucose", "goggles", "goldfish", "gremlin",
+    "guidance", "hamlet", "highchair", "hockey", "indoors", "indulge", "inverse", "involve", "island",
+    "jawbone", "keyboard", "kickoff", "kiwi", "klaxon", "locale", "lockup", "merit", "minnow", "miser",
+    "Mohawk", "mural", "music", "necklace", "Neptune", "newborn", "nightbird", "Oakland", "obtuse", "offload",
+    "optic", "orca", "payday", "peachy", "pheasant", "physique", "playhouse", "Pluto", "preclude", "prefer",
+    "preshrunk", "printer", "prowler", "pupil", "puppy", "python", "quadrant", "quiver", "quota", "ragtime",
+    "ratchet", "rebirth", "reform", "regain", "reindeer", "rematch", "repay", "retouch", "revenge", "reward",
+    "rhythm", "ribcage", "ringbolt", "robust", "rocker", "ruffled", "sailboat", "sawdust", "scallion",
+    "scenic", "scorecard", "Scotland", "seabird", "select", "sentence", "shadow", "shamrock", "showgirl",
+    "skullcap", "skydive", "slingshot", "slowdown", "snapline", "snapshot", "snowcap", "snowslide", "solo",
+    "southward", "soybean", "spaniel", "spearhead", "spellbind", "spheroid", "spigot", "spindle", "spyglass",
+    "stagehand", "stagnate", "stairway", "standard", "stapler", "steamship", "sterling", "stockman",
+    "stopwatch", "stormy", "sugar", "surmount", "suspense", "sweatband", "swelter", "tactics", "talon",
+    "tapeworm", "tempest", "tiger", "tissue", "tonic", "topmost", "tracker", "transit", "trauma", "treadmill",
+    "Trojan", "trouble", "tumor", "tunnel", "tycoon", "uncut", "unearth", "unwind", "uproot", "upset",
+    "upshot", "vapor", "village", "virus", "Vulcan", "waffle", "wallet", "watchword", "wayside", "willow",
+    "woodlark", "Zulu",
+];
+
+/// PGP word list for bytes at odd positions (three syllables).
+const ODD_WORDS: [&str; 256] = [
+    "adroitness", "adviser", "aftermath", "aggregate", "alkali", "almighty", "amulet", "amusement", "antenna",
+    "applicant", "Apollo", "armistice", "article", "asteroid", "Atlantic", "atmosphere", "autopsy", "Babylon",
+    "backwater", "barbecue", "belowground", "bifocals", "bodyguard", "bookseller", "borderline", "bottomless",
+    "Bradbury", "bravado", "Brazilian", "breakaway", "Burlington", "businessman", "butterfat", "Camelot",
+    "candidate", "cannonball", "Capricorn", "caravan", "caretaker", "celebrate", "cellulose", "certify",
+    "chambermaid", "Cherokee", "Chicago", "clergyman", "coherence", "combustion", "commando", "company",
+    "component", "concurrent", "confidence", "conformist", "congregate", "consensus", "consulting",
+    "corporate", "corrosion", "councilman", "crossover", "crucifix", "cumbersome", "customer", "Dakota",
+    "decadence", "December", "decimal", "designing", "detector", "detergent", "determine", "dictator",
+    "dinosaur", "direction", "disable", "disbelief", "disruptive", "distortion", "document", "embezzle",
+    "enchanting", "enrollment", "enterprise", "equation", "equipment", "escapade", "Eskimo", "everyday",
+    "examine", "existence", "exodus", "fascinate", "filament", "finicky", "forever", "fortitude", "frequency",
+    "gadgetry", "Galveston", "getaway", "glossary", "gossamer", "graduate", "gravity", "guitarist",
+    "hamburger", "Hamilton", "handiwork", "hazardous", "headwaters", "hemisphere", "hesitate", "hideaway",
+    "holiness", "hurricane", "hydraulic", "impartial", "impetus", "inception", "indigo", "inertia", "infancy",
+    "inferno", "informant", "insincere", "insurgent", "integrate", "intention", "inventive", "Istanbul",
+    "Jamaica", "Jupiter", "leprosy", "letterhead", "liberty", "maritime", "matchmaker", "maverick", "Medusa",
+    "megaton", "microscope", "microwave", "midsummer", "millionaire", "miracle", "misnomer", "molasses",
+    "molecule", "Montana", "monument", "mosquito", "narrative", "nebula", "newsletter", "Norwegian",
+    "October", "Ohio", "onlooker", "opulent", "Orlando", "outfielder", "Pacific", "pandemic", "Pandora",
+    "paperweight", "paragon", "paragraph", "paramount", "passenger", "pedigree", "Pegasus", "penetrate",
+    "perceptive", "performance", "pharmacy", "phonetic", "photograph", "pioneer", "pocketful", "politeness",
+    "positive", "potato", "processor", "provincial", "proximity", "puberty", "publisher", "pyramid",
+    "quantity", "racketeer", "rebellion", "recipe", "recover", "repellent", "replica", "reproduce",
+    "resistor", "responsive", "retraction", "retrieval", "retrospect", "revenue", "revival", "revolver",
+    "sandalwood", "sardonic", "Saturday", "savagery", "scavenger", "sensation", "sociable", "souvenir",
+    "specialist", "speculate", "stethoscope", "stupendous", "supportive", "surrender", "suspicious",
+    "sympathy", "tambourine", "telephone", "therapist", "tobacco", "tolerance", "tomorrow", "torpedo",
+    "tradition", "travesty", "trombonist", "truncated", "typewriter", "ultimate", "undaunted", "underfoot",
+    "unicorn", "unify", "universe", "unravel", "upcoming", "vacancy", "vagabond", "vertigo", "Virginia",
+    "visitor", "vocalist", "voyager", "warranty", "Waterloo", "whimsical", "Wichita", "Wilmington", "Wyoming",
+    "yesteryear", "Yucatan",
+];
+
+impl DispnetHash {
+    /// Words of the [PGP word list](https://en.wikipedia.org/wiki/PGP_word_list) for the first `n` digest bytes
+    /// (at most the digest length), for comparing hashes by reading them out loud.
+    ///
+    /// Even and odd byte positions use different word lists, so swapped or skipped words are detected.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn read_out_loud() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     assert_eq!(dispnet_hash.fingerprint_words(4), vec!["deadbolt", "indigo", "spellbind", "alkali"]);
+    /// }
+    /// ```
+    pub fn fingerprint_words(&self, n: usize) -> Vec<&'static str> {
+        self.digest_value
+            .iter()
+            .take(n)
+            .enumerate()
+            .map(|(position, byte)| word_list(position)[*byte as usize])
+            .collect()
+    }
+
+    /// Digest bytes of whitespace separated fingerprint words (see [`DispnetHash::fingerprint_words`]).
+    /// The words are matched case-insensitive.
+    pub fn parse_fingerprint_words(words: &str) -> Result<Vec<u8>, HashError> {
+        words
+            .split_whitespace()
+            .enumerate()
+            .map(|(position, word)| {
+                word_list(position)
+                    .iter()
+                    .position(|list_word| list_word.eq_ignore_ascii_case(word))
+                    .map(|byte| byte as u8)
+                    .ok_or_else(|| HashError::InvalidFingerprint {
+                        fingerprint: words.to_owned(),
+                    })
+            })
+            .collect()
+    }
+
+    /// True if the spoken fingerprint words match the start of the digest.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn compare_over_phone() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     assert!(dispnet_hash.matches_fingerprint_words("deadbolt indigo spellbind alkali"));
+    ///     assert!(!dispnet_hash.matches_fingerprint_words("deadbolt spellbind"));
+    /// }
+    /// ```
+    pub fn matches_fingerprint_words(&self, words: &str) -> bool {
+        match DispnetHash::parse_fingerprint_words(words) {
+            Ok(fingerprint) => !fingerprint.is_empty() && self.digest_value.starts_with(&fingerprint),
+            Err(_) => false,
+        }
+    }
+
+    /// Digest as colon separated hex byte pairs, like SSH key fingerprints.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn fingerprint_hex() {
+    ///     let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+    ///     assert_eq!(dispnet_hash.fingerprint_hex(), "32:32:35:38:36:36:32:30:38:30");
+    /// }
+    /// ```
+    pub fn fingerprint_hex(&self) -> String {
+        let mut fingerprint = String::with_capacity(3 * self.digest_value.len());
+        for (index, byte) in self.digest_value.iter().enumerate() {
+            if index > 0 {
+                fingerprint.push(':');
+            }
+            fingerprint.push_str(&DispnetHash::bytes_to_hex(&[*byte]));
+        }
+        fingerprint
+    }
+
+    /// Create a hash of the type from its fingerprint (see [`DispnetHash::fingerprint_hex`]).
+    /// Upper and lower case hex is accepted, every byte must have two digits.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn from_fingerprint_hex() {
+    ///     let dispnet_hash = DispnetHash::from_fingerprint_hex(HashType::CRC, "32:32:35:38:36:36:32:30:38:30").unwrap();
+    ///     assert_eq!(dispnet_hash, DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+    /// }
+    /// ```
+    pub fn from_fingerprint_hex(hash_type: HashType, fingerprint: &str) -> Result<Self, HashError> {
+        let invalid_fingerprint = || HashError::InvalidFingerprint {
+            fingerprint: fingerprint.to_owned(),
+        };
+        let digest = fingerprint
+            .split(':')
+            .map(|pair| match pair.len() {
+                2 => DispnetHash::hex_to_bytes(pair).map(|byte| byte[0]).ok_or_else(invalid_fingerprint),
+                _ => Err(invalid_fingerprint()),
+            })
+            .collect::<Result<Vec<u8>, HashError>>()?;
+        DispnetHash::from_raw_digest(hash_type, &digest)
+    }
+}
+
+fn word_list(position: usize) -> &'static [&'static str; 256] {
+    if position.is_multiple_of(2) {
+        &EVEN_WORDS
+    } else {
+        &ODD_WORDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType};
+
+    #[test]
+    fn pgp_word_list() {
+        let digest = [
+            0xE5, 0x82, 0x94, 0xF2, 0xE9, 0xA2, 0x27, 0x48, 0x6E, 0x8B, 0x06, 0x1B, 0x31, 0xCC, 0x52, 0x8F, 0xD7, 0xFA, 0x3F, 0x19,
+        ];
+        let dispnet_hash = DispnetHash::from_raw_digest(HashType::GitSha1, &digest).unwrap();
+        let words = "topmost Istanbul Pluto vagabond treadmill Pacific brackish dictator goldfish Medusa \
+                     afflict bravado chatter revolver Dupont midsummer stopwatch whimsical cowbell bottomless";
+        assert_eq!(dispnet_hash.fingerprint_words(20).join(" "), words.split_whitespace().collect::<Vec<_>>().join(" "));
+        assert_eq!(dispnet_hash.fingerprint_words(100).len(), 20);
+        assert_eq!(DispnetHash::parse_fingerprint_words(words).unwrap(), digest);
+    }
+
+    #[test]
+    fn parse_fingerprint_words() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let words = dispnet_hash.fingerprint_words(6).join(" ").to_uppercase();
+        assert!(dispnet_hash.matches_fingerprint_words(&words));
+        assert!(!DispnetHash::new("other".as_bytes()).matches_fingerprint_words(&words));
+        assert!(!dispnet_hash.matches_fingerprint_words(""));
+        // Words of the even list are not valid at odd positions.
+        assert!(matches!(
+            DispnetHash::parse_fingerprint_words("topmost topmost"),
+            Err(HashError::InvalidFingerprint { .. })
+        ));
+    }
+
+    #[test]
+    fn fingerprint_hex() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let fingerprint = dispnet_hash.fingerprint_hex();
+        assert!(fingerprint.starts_with("48:78:ca:04:"));
+        assert_eq!(fingerprint.len(), 32 * 3 - 1);
+        assert_eq!(DispnetHash::from_fingerprint_hex(HashType::Blake3, &fingerprint.to_uppercase()).unwrap(), dispnet_hash);
+        for fingerprint in ["", "48:78:c", "48:78:ca:", "4878:ca", "48:78:zz"] {
+            assert!(matches!(
+                DispnetHash::from_fingerprint_hex(HashType::Blake3, fingerprint),
+                Err(HashError::InvalidFingerprint { .. })
+            ));
+        }
+    }
+}
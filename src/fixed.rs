@@ -0,0 +1,244 @@
+use core::str::from_utf8;
+
+#[cfg(feature = "alloc")]
+use crate::{DispnetHash, HashError};
+use crate::{digest_fits_type, HashType, HEX_DIGITS, HEX_VALUES, INVALID_HEX};
+
+/// Dispnet hash with the digest in a fixed size array, usable without an allocator
+/// (`default-features = false`, the crate is `no_std`). `N` must be at most 9999 (checked at compile time).
+///
+/// The display format is the same as for `DispnetHash` but written into a provided buffer
+/// of at least [`DispnetHashFixed::ENCODED_LEN`] bytes.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, DispnetHashFixed};
+///
+/// fn fixed_hash() {
+///     let fixed_hash = DispnetHashFixed::blake3("test".as_bytes());
+///     let mut buffer = [0u8; DispnetHashFixed::<32>::ENCODED_LEN];
+///     let display_hash = fixed_hash.format_into(&mut buffer).unwrap();
+///     assert_eq!(display_hash, DispnetHash::new("test".as_bytes()).to_string());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispnetHashFixed<const N: usize> {
+    pub hash_type: HashType,
+    pub digest_value: [u8; N],
+}
+
+/// Digest with the length `N` in the type, APIs can require e.g. a 32 byte digest at compile time.
+///
+/// Converting a [`DispnetHash`] fails if the digest does not have exactly `N` bytes.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, HashType, TypedDigest};
+///
+/// fn store_key(digest: TypedDigest<32>) -> [u8; 32] {
+///     digest.digest_value
+/// }
+///
+/// fn typed_digest() {
+///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+///     assert_eq!(store_key(dispnet_hash.typed_digest().unwrap()).as_slice(), dispnet_hash.digest_value.as_slice());
+///     assert!(DispnetHash::create(HashType::GitSha1, "test".as_bytes(), None).typed_digest::<32>().is_err());
+/// }
+/// ```
+pub type TypedDigest<const N: usize> = DispnetHashFixed<N>;
+
+/// Error of [`DispnetHashFixed`], it holds no heap data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum FixedHashError {
+    /// The type code is not decimal.
+    InvalidType,
+    /// The digest length is missing or not decimal.
+    DigestLength,
+    /// The digest is not hex.
+    InvalidDigest,
+    UnknownHashType { type_code: u8 },
+    InvalidDigestForType { hash_type: HashType, length: usize },
+    BufferTooSmall { required: usize, available: usize },
+}
+
+impl<const N: usize> DispnetHashFixed<N> {
+    /// Length of the display format (type, digest length and hex digest).
+    pub const ENCODED_LEN: usize = 6 + 2 * N;
+
+    /// The digest length has 4 decimal digits in the display format.
+    const DIGEST_LENGTH_FITS: () = assert!(N <= 9999, "the digest length must be at most 9999 bytes");
+
+    pub fn new(hash_type: HashType, digest_value: [u8; N]) -> Self {
+        let () = Self::DIGEST_LENGTH_FITS;
+        Self { hash_type, digest_value }
+    }
+
+    /// Write the display format into the buffer and return the written part.
+    pub fn format_into<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, FixedHashError> {
+        let () = Self::DIGEST_LENGTH_FITS;
+        if buffer.len() < Self::ENCODED_LEN {
+            return Err(FixedHashError::BufferTooSmall {
+                required: Self::ENCODED_LEN,
+                available: buffer.len(),
+            });
+        }
+        let type_code = self.hash_type.code();
+        buffer[0] = b'0' + type_code / 10;
+        buffer[1] = b'0' + type_code % 10;
+        let mut digest_length = N;
+        for position in (2..6).rev() {
+            buffer[position] = b'0' + (digest_length % 10) as u8;
+            digest_length /= 10;
+        }
+        for (i, byte) in self.digest_value.iter().enumerate() {
+            buffer[6 + 2 * i] = HEX_DIGITS[(byte >> 4) as usize];
+            buffer[7 + 2 * i] = HEX_DIGITS[(byte & 0x0f) as usize];
+        }
+        // Only ASCII digits and hex characters are written.
+        Ok(from_utf8(&buffer[..Self::ENCODED_LEN]).unwrap())
+    }
+
+    /// Parse the display format, the digest length must be `N`.
+    pub fn parse(hash_value: &str) -> Result<Self, FixedHashError> {
+        let bytes = hash_value.as_bytes();
+        if bytes.len() < 6 {
+            return Err(FixedHashError::DigestLength);
+        }
+        let type_code = parse_decimal(&bytes[..2]).ok_or(FixedHashError::InvalidType)?;
+        let hash_type = HashType::from_code(type_code as u8)
+            .ok_or(FixedHashError::UnknownHashType { type_code: type_code as u8 })?;
+        let digest_length = parse_decimal(&bytes[2..6]).ok_or(FixedHashError::DigestLength)?;
+        let hex_digest = &bytes[6..];
+        if digest_length != N || hex_digest.len() != 2 * N {
+            return Err(FixedHashError::InvalidDigestForType {
+                hash_type,
+                length: hex_digest.len() / 2,
+            });
+        }
+        let mut digest_value = [0u8; N];
+        for (byte, pair) in digest_value.iter_mut().zip(hex_digest.chunks_exact(2)) {
+            let high = HEX_VALUES[pair[0] as usize];
+            let low = HEX_VALUES[pair[1] as usize];
+            if high == INVALID_HEX || low == INVALID_HEX {
+                return Err(FixedHashError::InvalidDigest);
+            }
+            *byte = (high << 4) | low;
+        }
+        if !digest_fits_type(hash_type, digest_value.iter().copied()) {
+            return Err(FixedHashError::InvalidDigestForType { hash_type, length: N });
+        }
+        Ok(Self { hash_type, digest_value })
+    }
+}
+
+/// Logs the display format without allocating (e.g. over RTT on a `no_std` target).
+#[cfg(feature = "defmt")]
+impl<const N: usize> defmt::Format for DispnetHashFixed<N> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=u8:02}{=usize:04}", self.hash_type.code(), N);
+        for byte in self.digest_value {
+            defmt::write!(f, "{=u8:02x}", byte);
+        }
+    }
+}
+
+impl DispnetHashFixed<32> {
+    /// Create a Blake3 hash without allocating.
+    pub fn blake3(value: &[u8]) -> Self {
+        Self::new(HashType::Blake3, *blake3::hash(value).as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<DispnetHashFixed<N>> for DispnetHash {
+    type Error = HashError;
+
+    /// Fails if the digest does not fit the hash type (e.g. 4 bytes for a CRC).
+    fn try_from(fixed_hash: DispnetHashFixed<N>) -> Result<Self, HashError> {
+        DispnetHash::from_decoded_digest(fixed_hash.hash_type, fixed_hash.digest_value.to_vec())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<&DispnetHash> for DispnetHashFixed<N> {
+    type Error = HashError;
+
+    fn try_from(hash: &DispnetHash) -> Result<Self, HashError> {
+        let digest_value = hash.digest_value.as_slice().try_into().map_err(|_| HashError::InvalidDigestForType {
+            hash_type: hash.hash_type,
+            length: hash.digest_length,
+        })?;
+        Ok(Self::new(hash.hash_type, digest_value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<DispnetHash> for DispnetHashFixed<N> {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        DispnetHashFixed::try_from(&hash)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DispnetHash {
+    /// Digest with the length in the type, fails if the digest does not have `N` bytes (see [`TypedDigest`]).
+    pub fn typed_digest<const N: usize>(&self) -> Result<TypedDigest<N>, HashError> {
+        TypedDigest::try_from(self)
+    }
+}
+
+pub(crate) fn parse_decimal(digits: &[u8]) -> Option<usize> {
+    digits.iter().try_fold(0usize, |value, digit| {
+        digit.is_ascii_digit().then(|| value * 10 + (digit - b'0') as usize)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, DispnetHashFixed, FixedHashError, HashError, HashType, TypedDigest};
+
+    #[test]
+    fn fixed_round_trip() {
+        let hash = DispnetHash::create(HashType::GitSha1, "test".as_bytes(), None);
+        let fixed_hash = DispnetHashFixed::<20>::try_from(&hash).unwrap();
+        let mut buffer = [0u8; 64];
+        let display_hash = fixed_hash.format_into(&mut buffer).unwrap();
+        assert_eq!(display_hash, hash.to_string());
+        assert_eq!(DispnetHashFixed::<20>::parse(display_hash).unwrap(), fixed_hash);
+        assert_eq!(DispnetHash::try_from(fixed_hash).unwrap(), hash);
+        assert_eq!(DispnetHash::try_from(DispnetHashFixed::blake3("test".as_bytes())).unwrap(), DispnetHash::new("test".as_bytes()));
+    }
+
+    #[test]
+    fn fixed_errors() {
+        let fixed_hash = DispnetHashFixed::blake3("test".as_bytes());
+        assert!(matches!(fixed_hash.format_into(&mut [0u8; 69]), Err(FixedHashError::BufferTooSmall { required: 70, available: 69 })));
+        assert!(matches!(DispnetHashFixed::<32>::parse("02001032323538363632303830"), Err(FixedHashError::InvalidDigestForType { .. })));
+        assert!(matches!(DispnetHashFixed::<4>::parse("01000474657g74"), Err(FixedHashError::InvalidDigest)));
+        assert!(matches!(DispnetHashFixed::<4>::parse("0100"), Err(FixedHashError::DigestLength)));
+        assert!(DispnetHashFixed::<20>::try_from(&DispnetHash::new("test".as_bytes())).is_err());
+    }
+
+    #[test]
+    fn fixed_digest_must_fit_type() {
+        assert!(matches!(DispnetHashFixed::<4>::parse("0200040000ffff"), Err(FixedHashError::InvalidDigestForType { hash_type: HashType::CRC, length: 4 })));
+        let crc_hash = DispnetHashFixed::new(HashType::CRC, [0, 0, 0xff, 0xff]);
+        assert!(matches!(DispnetHash::try_from(crc_hash), Err(HashError::InvalidDigestForType { hash_type: HashType::CRC, length: 4 })));
+        let blake3_hash = DispnetHashFixed::new(HashType::Blake3, [1, 2, 3]);
+        assert!(matches!(DispnetHash::try_from(blake3_hash), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 3 })));
+    }
+
+    #[test]
+    fn typed_digest_length() {
+        let hash = DispnetHash::new("test".as_bytes());
+        let digest: TypedDigest<32> = hash.typed_digest().unwrap();
+        assert_eq!(digest.digest_value.as_slice(), hash.digest_value.as_slice());
+        assert_eq!(TypedDigest::<32>::try_from(hash.clone()).unwrap(), digest);
+        assert!(matches!(hash.typed_digest::<20>(), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 32 })));
+        assert!(DispnetHash::create(HashType::GitSha1, "test".as_bytes(), None).typed_digest::<20>().is_ok());
+    }
+}
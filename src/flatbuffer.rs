@@ -0,0 +1,118 @@
+use flatbuffers::{FlatBufferBuilder, Follow, ForwardsUOffset, Table, Vector, Verifiable, Verifier, WIPOffset};
+
+use crate::{DispnetHash, HashError, HashType};
+
+/// Read access to the `DispnetHash` table of `fbs/dispnet_hash.fbs` (like the code generated by `flatc`).
+#[derive(Clone, Copy, PartialEq)]
+pub struct DispnetHashTable<'a> {
+    table: Table<'a>,
+}
+
+impl<'a> DispnetHashTable<'a> {
+    const VT_HASH_TYPE: flatbuffers::VOffsetT = 4;
+    const VT_DIGEST: flatbuffers::VOffsetT = 6;
+
+    pub fn hash_type(&self) -> u8 {
+        // Safety: the table was verified when it was read with `unpack_flatbuffer`.
+        unsafe { self.table.get::<u8>(Self::VT_HASH_TYPE, Some(0)).unwrap_or(0) }
+    }
+
+    pub fn digest(&self) -> Option<&'a [u8]> {
+        // Safety: the table was verified when it was read with `unpack_flatbuffer`.
+        unsafe {
+            self.table
+                .get::<ForwardsUOffset<Vector<'a, u8>>>(Self::VT_DIGEST, None)
+                .map(|digest| digest.bytes())
+        }
+    }
+}
+
+impl<'a> Follow<'a> for DispnetHashTable<'a> {
+    type Inner = DispnetHashTable<'a>;
+
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            table: Table::new(buf, loc),
+        }
+    }
+}
+
+impl Verifiable for DispnetHashTable<'_> {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<u8>("hash_type", Self::VT_HASH_TYPE, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, u8>>>("digest", Self::VT_DIGEST, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Add the hash as `DispnetHash` table to the builder (e.g. as field of another table).
+pub fn pack_flatbuffer<'a>(builder: &mut FlatBufferBuilder<'a>, hash: &DispnetHash) -> WIPOffset<DispnetHashTable<'a>> {
+    let digest = builder.create_vector(&hash.digest_value);
+    let table = builder.start_table();
+    builder.push_slot_always::<WIPOffset<_>>(DispnetHashTable::VT_DIGEST, digest);
+    builder.push_slot::<u8>(DispnetHashTable::VT_HASH_TYPE, hash.hash_type.code(), 0);
+    WIPOffset::new(builder.end_table(table).value())
+}
+
+/// Read a hash from a `DispnetHashTable` (e.g. a field of another table).
+pub fn unpack_flatbuffer_table(table: DispnetHashTable<'_>) -> Result<DispnetHash, HashError> {
+    let hash_type = HashType::from_code(table.hash_type()).ok_or(HashError::UnknownHashType {
+        type_code: table.hash_type(),
+    })?;
+    DispnetHash::from_decoded_digest(hash_type, table.digest().unwrap_or_default().to_vec())
+}
+
+/// Read a hash from a buffer with a `DispnetHash` root table.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{pack_flatbuffer, unpack_flatbuffer, DispnetHash};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// fn flatbuffer() {
+///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+///     let mut builder = FlatBufferBuilder::new();
+///     let root = pack_flatbuffer(&mut builder, &dispnet_hash);
+///     builder.finish(root, None);
+///     assert_eq!(unpack_flatbuffer(builder.finished_data()).unwrap(), dispnet_hash);
+/// }
+/// ```
+pub fn unpack_flatbuffer(buffer: &[u8]) -> Result<DispnetHash, HashError> {
+    let table = flatbuffers::root::<DispnetHashTable>(buffer).map_err(|error| HashError::Serialization {
+        message: error.to_string(),
+    })?;
+    unpack_flatbuffer_table(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use flatbuffers::FlatBufferBuilder;
+
+    use crate::{pack_flatbuffer, unpack_flatbuffer, DispnetHash, HashError, HashType};
+
+    #[test]
+    fn flatbuffer_round_trip() {
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::GitSha1] {
+            let dispnet_hash = DispnetHash::create(hash_type, "test".as_bytes(), None);
+            let mut builder = FlatBufferBuilder::new();
+            let root = pack_flatbuffer(&mut builder, &dispnet_hash);
+            builder.finish(root, None);
+            assert_eq!(unpack_flatbuffer(builder.finished_data()).unwrap(), dispnet_hash);
+        }
+    }
+
+    #[test]
+    fn invalid_flatbuffer() {
+        assert!(matches!(unpack_flatbuffer(&[1, 2, 3]), Err(HashError::Serialization { .. })));
+    }
+
+    #[test]
+    fn flatbuffer_digest_must_fit_type() {
+        let mut builder = FlatBufferBuilder::new();
+        let root = pack_flatbuffer(&mut builder, &DispnetHash::from_digest(HashType::CRC, vec![0xff]));
+        builder.finish(root, None);
+        assert!(matches!(unpack_flatbuffer(builder.finished_data()), Err(HashError::InvalidDigestForType { hash_type: HashType::CRC, length: 1 })));
+    }
+}
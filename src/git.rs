@@ -0,0 +1,73 @@
+use alloc::format;
+use core::fmt;
+
+use sha1::{Digest, Sha1};
+
+use crate::{DispnetHash, HashType};
+
+/// Git object kind which is part of the object header (`<kind> <length>\0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GitObjectKind {
+    Blob,
+    Tree,
+    Commit,
+    Tag,
+}
+
+impl fmt::Display for GitObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GitObjectKind::Blob => write!(f, "blob"),
+            GitObjectKind::Tree => write!(f, "tree"),
+            GitObjectKind::Commit => write!(f, "commit"),
+            GitObjectKind::Tag => write!(f, "tag"),
+        }
+    }
+}
+
+pub(crate) fn object_digest(kind: GitObjectKind, content: &[u8]) -> [u8; 20] {
+    let mut hasher = Sha1::new();
+    hasher.update(format!("{} {}\0", kind, content.len()).as_bytes());
+    hasher.update(content);
+    hasher.finalize().into()
+}
+
+impl DispnetHash {
+    /// Create a git compatible object hash (SHA-1 with git object header).
+    ///
+    /// The digest equals the object id git uses for the content (`git hash-object -t <kind>`).
+    /// Creating a hash with `HashType::GitSha1` hashes the value as blob.
+    /// Tree, commit and tag content must already be in the git object format.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, GitObjectKind};
+    ///
+    /// fn git_blob_hash() {
+    ///     let dispnet_hash = DispnetHash::create_git_object(GitObjectKind::Blob, "test\n".as_bytes());
+    ///     assert_eq!(DispnetHash::bytes_to_hex(&dispnet_hash.digest_value), "9daeafb9864cf43055ae93beb0afd6c7d144bfa4");
+    /// }
+    /// ```
+    pub fn create_git_object(kind: GitObjectKind, content: &[u8]) -> DispnetHash {
+        let hash_bytes = object_digest(kind, content);
+        DispnetHash::from_digest(HashType::GitSha1, hash_bytes.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, GitObjectKind, HashType};
+
+    #[test]
+    fn git_blob_hash() {
+        let dispnet_hash = DispnetHash::create(HashType::GitSha1, "test\n".as_bytes(), None);
+        assert_eq!(dispnet_hash.to_string(), "0500209daeafb9864cf43055ae93beb0afd6c7d144bfa4");
+        assert_eq!(dispnet_hash, DispnetHash::create_git_object(GitObjectKind::Blob, "test\n".as_bytes()));
+        assert_eq!(dispnet_hash.to_string().parse::<DispnetHash>().unwrap().hash_type, HashType::GitSha1);
+    }
+
+    #[test]
+    fn git_tree_hash() {
+        let dispnet_hash = DispnetHash::create_git_object(GitObjectKind::Tree, &[]);
+        assert_eq!(DispnetHash::bytes_to_hex(&dispnet_hash.digest_value), "4b825dc642cb6eb9a060e54bf8d69288fbee4904");
+    }
+}
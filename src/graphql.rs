@@ -0,0 +1,53 @@
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::DispnetHash;
+
+/// GraphQL scalar `DispnetHash` in the display format.
+/// Input values are validated like [`DispnetHash::from_str`](std::str::FromStr).
+#[Scalar(name = "DispnetHash")]
+impl ScalarType for DispnetHash {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(raw_hash) => raw_hash
+                .parse::<DispnetHash>()
+                .map_err(|error| InputValueError::custom(format!("Invalid dispnet hash: {:?}", error))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(raw_hash) if raw_hash.parse::<DispnetHash>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    use crate::DispnetHash;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn crc(&self, hash: DispnetHash) -> DispnetHash {
+            hash
+        }
+    }
+
+    #[tokio::test]
+    async fn hash_scalar() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = schema.execute(r#"{ crc(hash: "02001032323538363632303830") }"#).await;
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.to_string(), r#"{crc: "02001032323538363632303830"}"#);
+
+        let response = schema.execute(r#"{ crc(hash: "0200103232") }"#).await;
+        assert_eq!(response.errors.len(), 1);
+        assert!(schema.sdl().contains("scalar DispnetHash"));
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{argon2_backend::MIN_SALT_LENGTH, DispnetHash, HashConfig, HashError, HashType, SecurityClass};
+
+/// Hash of content (every hash type except password hashes) which can be compared and used as key.
+///
+/// The display format and the serde format are the ones of [`DispnetHash`].
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{ContentHash, HashType};
+///
+/// fn content_hash() {
+///     let content_hash = ContentHash::new("test".as_bytes());
+///     assert_eq!(content_hash, ContentHash::create(HashType::Blake3, "test".as_bytes()).unwrap());
+///     assert!(content_hash.matches("test".as_bytes()));
+///     assert!(ContentHash::create(HashType::Argon2, "test".as_bytes()).is_err());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentHash(DispnetHash);
+
+/// Password hash (Argon2) which can only be verified, it has no equality on purpose.
+///
+/// Comparing password hashes is a mistake: the same password has a different hash for every salt.
+/// The display format and the serde format are the ones of [`DispnetHash`].
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::PasswordHash;
+///
+/// fn login() {
+///     let password_hash = PasswordHash::create(b"secret", b"12345678").unwrap();
+///     let stored_hash = password_hash.to_string();
+///     assert!(stored_hash.parse::<PasswordHash>().unwrap().verify(b"secret"));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PasswordHash(DispnetHash);
+
+impl ContentHash {
+    /// Blake3 content hash.
+    pub fn new(value: &[u8]) -> Self {
+        Self(DispnetHash::create(HashType::Blake3, value, None))
+    }
+
+    /// Content hash of the type, password hash types are rejected.
+    pub fn create(hash_type: HashType, value: &[u8]) -> Result<Self, HashError> {
+        check_content_type(hash_type)?;
+        Ok(Self(DispnetHash::create(hash_type, value, None)))
+    }
+
+    /// True if the value has this hash.
+    #[must_use]
+    pub fn matches(&self, value: &[u8]) -> bool {
+        DispnetHash::verify_instance(&self.0, value)
+    }
+
+    pub fn as_dispnet_hash(&self) -> &DispnetHash {
+        &self.0
+    }
+}
+
+impl PasswordHash {
+    /// Argon2 hash of the password with the salt (at least 8 bytes, use a random salt per password).
+    pub fn create(password: &[u8], salt: &[u8]) -> Result<Self, HashError> {
+        if salt.len() < MIN_SALT_LENGTH {
+            return Err(HashError::SaltTooShort {
+                length: salt.len(),
+                min_length: MIN_SALT_LENGTH,
+            });
+        }
+        let config = HashConfig::default().with_salt(salt);
+        Ok(Self(DispnetHash::create(HashType::Argon2, password, Some(config))))
+    }
+
+    /// True if the password matches the hash.
+    #[must_use]
+    pub fn verify(&self, password: &[u8]) -> bool {
+        DispnetHash::verify_instance(&self.0, password)
+    }
+
+    pub fn as_dispnet_hash(&self) -> &DispnetHash {
+        &self.0
+    }
+}
+
+fn check_content_type(hash_type: HashType) -> Result<(), HashError> {
+    if hash_type.security_class() == SecurityClass::PasswordHash {
+        return Err(HashError::UnexpectedHashType { hash_type });
+    }
+    Ok(())
+}
+
+fn check_password_type(hash_type: HashType) -> Result<(), HashError> {
+    if hash_type.security_class() != SecurityClass::PasswordHash {
+        return Err(HashError::UnexpectedHashType { hash_type });
+    }
+    Ok(())
+}
+
+impl Eq for ContentHash {}
+
+impl Hash for ContentHash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+impl TryFrom<DispnetHash> for ContentHash {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        check_content_type(hash.hash_type)?;
+        Ok(Self(hash))
+    }
+}
+
+impl TryFrom<DispnetHash> for PasswordHash {
+    type Error = HashError;
+
+    fn try_from(hash: DispnetHash) -> Result<Self, HashError> {
+        check_password_type(hash.hash_type)?;
+        Ok(Self(hash))
+    }
+}
+
+impl From<ContentHash> for DispnetHash {
+    fn from(content_hash: ContentHash) -> Self {
+        content_hash.0
+    }
+}
+
+impl From<PasswordHash> for DispnetHash {
+    fn from(password_hash: PasswordHash) -> Self {
+        password_hash.0
+    }
+}
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for PasswordHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for ContentHash {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        ContentHash::try_from(s.parse::<DispnetHash>()?)
+    }
+}
+
+impl FromStr for PasswordHash {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        PasswordHash::try_from(s.parse::<DispnetHash>()?)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{ContentHash, DispnetHash, PasswordHash};
+
+    impl Serialize for ContentHash {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ContentHash {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let hash = DispnetHash::deserialize(deserializer)?;
+            ContentHash::try_from(hash).map_err(|error| de::Error::custom(format!("{:?}", error)))
+        }
+    }
+
+    impl Serialize for PasswordHash {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PasswordHash {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let hash = DispnetHash::deserialize(deserializer)?;
+            PasswordHash::try_from(hash).map_err(|error| de::Error::custom(format!("{:?}", error)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{ContentHash, DispnetHash, HashError, HashType, PasswordHash};
+
+    #[test]
+    fn content_hash() {
+        let content_hash = ContentHash::create(HashType::CRC, "test".as_bytes()).unwrap();
+        assert!(content_hash.matches("test".as_bytes()));
+        assert_eq!(content_hash.to_string().parse::<ContentHash>().unwrap(), content_hash);
+        assert_eq!(HashSet::from([content_hash.clone(), content_hash.clone()]).len(), 1);
+        let argon2_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
+        assert!(matches!(
+            ContentHash::try_from(argon2_hash.clone()),
+            Err(HashError::UnexpectedHashType { hash_type: HashType::Argon2 })
+        ));
+        assert!(argon2_hash.to_string().parse::<ContentHash>().is_err());
+    }
+
+    #[test]
+    fn password_hash() {
+        let password_hash = PasswordHash::create(b"secret", b"12345678").unwrap();
+        assert!(password_hash.verify(b"secret"));
+        assert!(!password_hash.verify(b"wrong"));
+        let dispnet_hash: DispnetHash = password_hash.clone().into();
+        assert_eq!(dispnet_hash.to_string(), password_hash.to_string());
+        assert!(password_hash.to_string().parse::<PasswordHash>().unwrap().verify(b"secret"));
+        assert!(matches!(
+            PasswordHash::create(b"secret", b"1234"),
+            Err(HashError::SaltTooShort { length: 4, min_length: 8 })
+        ));
+        assert!(PasswordHash::try_from(DispnetHash::new("test".as_bytes())).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_like_dispnet_hash() {
+        let content_hash = ContentHash::new("test".as_bytes());
+        let json = serde_json::to_string(&content_hash).unwrap();
+        assert_eq!(json, serde_json::to_string(content_hash.as_dispnet_hash()).unwrap());
+        assert_eq!(serde_json::from_str::<ContentHash>(&json).unwrap(), content_hash);
+        assert!(serde_json::from_str::<PasswordHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,422 @@
+use std::io;
+
+use blake3::hazmat::{
+    hash_derive_key_context, merge_subtrees_non_root, merge_subtrees_root, ChainingValue, ContextKey, HasherExt, Mode,
+};
+
+use crate::{context::context_prefix, DispnetHash, HashConfig, HashError, HashType};
+
+const STATE_VERSION: u8 = 1;
+const CONFIG_SALT: u8 = 1;
+const CONFIG_ARGON2_LANES: u8 = 2;
+const CONFIG_CONTEXT: u8 = 4;
+const BLAKE3_SUBTREE_LEN: usize = 16 * blake3::CHUNK_LEN;
+
+/// Streaming hasher which produces the same dispnet hash as [`DispnetHash::create`].
+///
+/// Blake3 and CRC hash the input incrementally, all other types buffer the input until `finalize`.
+/// The state can be exported and imported again to suspend and resume hashing (e.g. across process restarts).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, DispnetHasher, HashType};
+///
+/// fn resume_hashing() {
+///     let mut hasher = DispnetHasher::new(HashType::Blake3, None);
+///     hasher.update("te".as_bytes());
+///     let state = hasher.export_state();
+///
+///     let mut resumed_hasher = DispnetHasher::import_state(&state).unwrap();
+///     resumed_hasher.update("st".as_bytes());
+///     assert_eq!(resumed_hasher.finalize(), DispnetHash::new("test".as_bytes()));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DispnetHasher {
+    hash_type: HashType,
+    config: Option<HashConfig>,
+    /// Key derived from the config context, Blake3 hashes in key derivation mode with it.
+    context_key: Option<ContextKey>,
+    input_length: u64,
+    state: HasherState,
+}
+
+#[derive(Debug)]
+enum HasherState {
+    Blake3 {
+        subtrees: u64,
+        stack: Vec<ChainingValue>,
+        buffer: Vec<u8>,
+    },
+    Crc(u32),
+    Buffered(Vec<u8>),
+}
+
+impl DispnetHasher {
+    /// Create a streaming hasher for the hash type.
+    pub fn new(hash_type: HashType, config: Option<HashConfig>) -> Self {
+        let hash_type = hash_type.configured(config.as_ref());
+        let context = config.as_ref().and_then(|hash_config| hash_config.context.as_deref());
+        let state = match hash_type {
+            HashType::Blake3 => HasherState::Blake3 {
+                subtrees: 0,
+                stack: Vec::new(),
+                buffer: Vec::with_capacity(BLAKE3_SUBTREE_LEN),
+            },
+            // The context prefix is part of the checksum from the start.
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => {
+                HasherState::Crc(crc32(hash_type).checksum(&context.map(context_prefix).unwrap_or_default()))
+            }
+            _ => HasherState::Buffered(Vec::new()),
+        };
+        Self {
+            hash_type,
+            context_key: context.map(hash_derive_key_context),
+            config,
+            input_length: 0,
+            state,
+        }
+    }
+
+    /// Add input to the hash.
+    pub fn update(&mut self, mut value: &[u8]) {
+        self.input_length += value.len() as u64;
+        match &mut self.state {
+            HasherState::Blake3 {
+                subtrees,
+                stack,
+                buffer,
+            } => {
+                // A subtree is only hashed once more input follows, the last one must stay
+                // buffered because it is finalized differently (as root or right edge).
+                while !value.is_empty() {
+                    if buffer.len() == BLAKE3_SUBTREE_LEN {
+                        push_blake3_subtree(self.context_key.as_ref(), subtrees, stack, buffer);
+                        buffer.clear();
+                    }
+                    if buffer.is_empty() {
+                        while value.len() > BLAKE3_SUBTREE_LEN {
+                            push_blake3_subtree(self.context_key.as_ref(), subtrees, stack, &value[..BLAKE3_SUBTREE_LEN]);
+                            value = &value[BLAKE3_SUBTREE_LEN..];
+                        }
+                    }
+                    let take = (BLAKE3_SUBTREE_LEN - buffer.len()).min(value.len());
+                    buffer.extend_from_slice(&value[..take]);
+                    value = &value[take..];
+                }
+            }
+            HasherState::Crc(checksum) => {
+                let crc32 = crc32(self.hash_type);
+                let mut digest = crc32.digest_with_initial(crc_resume_initial(&crc32, *checksum));
+                digest.update(value);
+                *checksum = digest.finalize();
+            }
+            HasherState::Buffered(buffer) => buffer.extend_from_slice(value),
+        }
+    }
+
+    /// Count of bytes added to the hash so far.
+    pub fn input_length(&self) -> u64 {
+        self.input_length
+    }
+
+    /// False if the whole input is buffered until `finalize` (Argon2, SimHash and Git SHA-1).
+    pub(crate) fn is_streaming(&self) -> bool {
+        !matches!(self.state, HasherState::Buffered(_))
+    }
+
+    /// Hash type of the hasher.
+    pub fn hash_type(&self) -> &HashType {
+        &self.hash_type
+    }
+
+    /// Finish the hash.
+    pub fn finalize(self) -> DispnetHash {
+        match self.state {
+            HasherState::Blake3 {
+                subtrees,
+                stack,
+                buffer,
+            } => {
+                let context_key = self.context_key.as_ref();
+                let hash = if subtrees == 0 {
+                    blake3_hasher(context_key).update(&buffer).finalize()
+                } else {
+                    let mut right = blake3_hasher(context_key)
+                        .set_input_offset(subtrees * BLAKE3_SUBTREE_LEN as u64)
+                        .update(&buffer)
+                        .finalize_non_root();
+                    for left in stack[1..].iter().rev() {
+                        right = merge_subtrees_non_root(left, &right, blake3_mode(context_key));
+                    }
+                    merge_subtrees_root(&stack[0], &right, blake3_mode(context_key))
+                };
+                let hash_bytes = hash.as_bytes();
+                DispnetHash::from_digest(HashType::Blake3, hash_bytes.to_vec())
+            }
+            HasherState::Crc(checksum) => {
+                let hash = checksum.to_string();
+                DispnetHash::from_digest(self.hash_type, hash.into_bytes())
+            }
+            HasherState::Buffered(buffer) => DispnetHash::create(self.hash_type, &buffer, self.config),
+        }
+    }
+
+    /// Export the state of the hasher as bytes.
+    ///
+    /// The state contains at most one not yet hashed block of input (or the whole input for buffered hash types).
+    pub fn export_state(&self) -> Vec<u8> {
+        let mut state = vec![STATE_VERSION, self.hash_type.code()];
+        state.extend_from_slice(&self.input_length.to_be_bytes());
+        // Flags of the stored config options: 1 salt, 2 Argon2 lanes, 4 context.
+        let salt = self.config.as_ref().and_then(|config| config.salt.as_ref());
+        let argon2_lanes = self.config.as_ref().and_then(|config| config.argon2_lanes);
+        let context = self.config.as_ref().and_then(|config| config.context.as_ref());
+        state.push(
+            salt.map_or(0, |_| CONFIG_SALT)
+                | argon2_lanes.map_or(0, |_| CONFIG_ARGON2_LANES)
+                | context.map_or(0, |_| CONFIG_CONTEXT),
+        );
+        if let Some(salt) = salt {
+            state.extend_from_slice(&(salt.len() as u32).to_be_bytes());
+            state.extend_from_slice(salt);
+        }
+        if let Some(argon2_lanes) = argon2_lanes {
+            state.extend_from_slice(&argon2_lanes.to_be_bytes());
+        }
+        if let Some(context) = context {
+            state.extend_from_slice(&(context.len() as u32).to_be_bytes());
+            state.extend_from_slice(context.as_bytes());
+        }
+        match &self.state {
+            HasherState::Blake3 {
+                subtrees,
+                stack,
+                buffer,
+            } => {
+                state.extend_from_slice(&subtrees.to_be_bytes());
+                for chaining_value in stack {
+                    state.extend_from_slice(chaining_value);
+                }
+                state.extend_from_slice(buffer);
+            }
+            HasherState::Crc(checksum) => state.extend_from_slice(&checksum.to_be_bytes()),
+            HasherState::Buffered(buffer) => state.extend_from_slice(buffer),
+        }
+        state
+    }
+
+    /// Import a state created with [`DispnetHasher::export_state`].
+    pub fn import_state(state: &[u8]) -> Result<Self, HashError> {
+        let invalid_state = || HashError::InvalidHasherState {
+            state_length: state.len(),
+        };
+        let mut reader = StateReader(state);
+        if reader.take(1).ok_or_else(invalid_state)?[0] != STATE_VERSION {
+            return Err(invalid_state());
+        }
+        let type_code = reader.take(1).ok_or_else(invalid_state)?[0];
+        let hash_type = HashType::from_code(type_code).ok_or(HashError::UnknownHashType { type_code })?;
+        let input_length = reader.take_u64().ok_or_else(invalid_state)?;
+        let config_flags = reader.take(1).ok_or_else(invalid_state)?[0];
+        if config_flags & !(CONFIG_SALT | CONFIG_ARGON2_LANES | CONFIG_CONTEXT) != 0 {
+            return Err(invalid_state());
+        }
+        let config = if config_flags == 0 {
+            None
+        } else {
+            let mut config = HashConfig::default();
+            if config_flags & CONFIG_SALT != 0 {
+                let salt_length = reader.take_u32().ok_or_else(invalid_state)? as usize;
+                let salt = reader.take(salt_length).ok_or_else(invalid_state)?;
+                config.salt = Some(Box::new(salt.to_vec()));
+            }
+            if config_flags & CONFIG_ARGON2_LANES != 0 {
+                config.argon2_lanes = Some(reader.take_u32().ok_or_else(invalid_state)?);
+            }
+            if config_flags & CONFIG_CONTEXT != 0 {
+                let context_length = reader.take_u32().ok_or_else(invalid_state)? as usize;
+                let context = reader.take(context_length).ok_or_else(invalid_state)?;
+                config.context = Some(String::from_utf8(context.to_vec()).map_err(|_| invalid_state())?);
+            }
+            Some(config)
+        };
+        let hasher_state = match hash_type {
+            HashType::Blake3 => {
+                let subtrees = reader.take_u64().ok_or_else(invalid_state)?;
+                let stack = (0..subtrees.count_ones())
+                    .map(|_| reader.take(32).map(|cv| <ChainingValue>::try_from(cv).unwrap()))
+                    .collect::<Option<Vec<ChainingValue>>>()
+                    .ok_or_else(invalid_state)?;
+                let buffer = reader.0.to_vec();
+                let expected_length = subtrees
+                    .checked_mul(BLAKE3_SUBTREE_LEN as u64)
+                    .and_then(|length| length.checked_add(buffer.len() as u64));
+                let buffer_valid = buffer.len() <= BLAKE3_SUBTREE_LEN && (subtrees == 0 || !buffer.is_empty());
+                if expected_length != Some(input_length) || !buffer_valid {
+                    return Err(invalid_state());
+                }
+                HasherState::Blake3 {
+                    subtrees,
+                    stack,
+                    buffer,
+                }
+            }
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => {
+                let checksum = reader.take_u32().ok_or_else(invalid_state)?;
+                if !reader.0.is_empty() {
+                    return Err(invalid_state());
+                }
+                HasherState::Crc(checksum)
+            }
+            _ => {
+                if reader.0.len() as u64 != input_length {
+                    return Err(invalid_state());
+                }
+                HasherState::Buffered(reader.0.to_vec())
+            }
+        };
+        let context_key = config
+            .as_ref()
+            .and_then(|hash_config| hash_config.context.as_deref())
+            .map(hash_derive_key_context);
+        Ok(Self {
+            hash_type,
+            config,
+            context_key,
+            input_length,
+            state: hasher_state,
+        })
+    }
+}
+
+impl io::Write for DispnetHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn crc32(hash_type: HashType) -> crc::Crc<u32> {
+    crc::Crc::<u32>::new(hash_type.crc_variant().unwrap().algorithm())
+}
+
+/// Initial value which continues a CRC digest from an already finalized checksum.
+fn crc_resume_initial(crc32: &crc::Crc<u32>, checksum: u32) -> u32 {
+    let algorithm = crc32.algorithm;
+    let value = checksum ^ algorithm.xorout;
+    if algorithm.refin {
+        value.reverse_bits()
+    } else {
+        value
+    }
+}
+
+fn blake3_hasher(context_key: Option<&ContextKey>) -> blake3::Hasher {
+    context_key.map_or_else(blake3::Hasher::new, blake3::Hasher::new_from_context_key)
+}
+
+fn blake3_mode(context_key: Option<&ContextKey>) -> Mode<'_> {
+    context_key.map_or(Mode::Hash, Mode::DeriveKeyMaterial)
+}
+
+fn push_blake3_subtree(
+    context_key: Option<&ContextKey>,
+    subtrees: &mut u64,
+    stack: &mut Vec<ChainingValue>,
+    subtree: &[u8],
+) {
+    let mut chaining_value = blake3_hasher(context_key)
+        .set_input_offset(*subtrees * BLAKE3_SUBTREE_LEN as u64)
+        .update(subtree)
+        .finalize_non_root();
+    *subtrees += 1;
+    let mut total_subtrees = *subtrees;
+    while total_subtrees & 1 == 0 {
+        let left = stack.pop().unwrap();
+        chaining_value = merge_subtrees_non_root(&left, &chaining_value, blake3_mode(context_key));
+        total_subtrees >>= 1;
+    }
+    stack.push(chaining_value);
+}
+
+/// Reader of big-endian binary formats.
+pub(crate) struct StateReader<'a>(pub(crate) &'a [u8]);
+
+impl<'a> StateReader<'a> {
+    pub(crate) fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.0.len() < length {
+            return None;
+        }
+        let (value, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Some(value)
+    }
+
+    pub(crate) fn take_u16(&mut self) -> Option<u16> {
+        self.take(2).map(|value| u16::from_be_bytes(value.try_into().unwrap()))
+    }
+
+    pub(crate) fn take_u32(&mut self) -> Option<u32> {
+        self.take(4).map(|value| u32::from_be_bytes(value.try_into().unwrap()))
+    }
+
+    pub(crate) fn take_u64(&mut self) -> Option<u64> {
+        self.take(8).map(|value| u64::from_be_bytes(value.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, DispnetHasher, HashConfig, HashError, HashType};
+
+    fn test_data(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn hasher_matches_create() {
+        for length in [0, 1, 1024, 16 * 1024, 16 * 1024 + 1, 48 * 1024, 100_000, 300_000] {
+            let data = test_data(length);
+            for hash_type in [HashType::Blake3, HashType::CRC, HashType::CrcIeee, HashType::SimHash] {
+                let mut hasher = DispnetHasher::new(hash_type, None);
+                for part in data.chunks(7000) {
+                    hasher.update(part);
+                }
+                let expected = DispnetHash::create(*hasher.hash_type(), &data, None);
+                assert_eq!(hasher.finalize(), expected, "length {}", length);
+            }
+        }
+    }
+
+    #[test]
+    fn hasher_resume_state() {
+        let data = test_data(200_000);
+        for (hash_type, length) in [(HashType::Blake3, 200_000), (HashType::CRC, 200_000), (HashType::CrcKoopman, 200_000), (HashType::Argon2, 100)] {
+            for split in [0, 5, 16 * 1024, 70_000, 200_000] {
+                let split = split.min(length);
+                let mut hasher = DispnetHasher::new(hash_type, Some(HashConfig::default().with_salt(*b"12345678").with_argon2_lanes(2)));
+                hasher.update(&data[..split]);
+                let state = hasher.export_state();
+                let mut resumed_hasher = DispnetHasher::import_state(&state).unwrap();
+                assert_eq!(resumed_hasher.input_length(), split as u64);
+                resumed_hasher.update(&data[split..length]);
+                let expected = DispnetHash::create(hash_type, &data[..length], Some(HashConfig::default().with_salt(*b"12345678").with_argon2_lanes(2)));
+                assert_eq!(resumed_hasher.finalize(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn hasher_invalid_state() {
+        let mut hasher = DispnetHasher::new(HashType::Blake3, None);
+        hasher.update(&test_data(20_000));
+        let state = hasher.export_state();
+        assert!(matches!(DispnetHasher::import_state(&state[..state.len() - 100]), Err(HashError::InvalidHasherState { .. })));
+        assert!(matches!(DispnetHasher::import_state(&[]), Err(HashError::InvalidHasherState { .. })));
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "ulid")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "ulid")]
+use ulid::Ulid;
+#[cfg(feature = "uuid")]
+use uuid::{Builder, Uuid};
+
+use crate::DispnetHash;
+
+/// Key derivation context of the UUID bytes, changing it changes all derived UUIDs.
+#[cfg(feature = "uuid")]
+const UUID_CONTEXT: &str = "dispnet-hash 2026-10 uuid v8";
+/// Key derivation context of the ULID randomness, changing it changes all derived ULIDs.
+#[cfg(feature = "ulid")]
+const ULID_CONTEXT: &str = "dispnet-hash 2026-10 ulid";
+
+impl DispnetHash {
+    /// Stable UUID (version 8, RFC 9562 variant) derived from the hash.
+    ///
+    /// The 16 bytes are taken from a Blake3 key derivation of [`DispnetHash::to_bytes`], so the
+    /// hash type is part of the identity and digests of any length map to a uniform UUID.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn primary_key() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     let uuid = dispnet_hash.to_uuid();
+    ///     assert_eq!(uuid.get_version_num(), 8);
+    ///     assert_eq!(uuid, DispnetHash::new("test".as_bytes()).to_uuid());
+    /// }
+    /// ```
+    #[cfg(feature = "uuid")]
+    pub fn to_uuid(&self) -> Uuid {
+        let key = blake3::derive_key(UUID_CONTEXT, &self.to_bytes());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&key[..16]);
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+
+    /// ULID of the timestamp (milliseconds precision) with the 80 random bits derived from the hash.
+    ///
+    /// ULIDs sort by the timestamp, the same hash and timestamp always produce the same ULID.
+    /// Timestamps before the Unix epoch are clamped to the epoch.
+    /// # Usage
+    /// ```
+    /// use std::time::SystemTime;
+    ///
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn record_id(content: &[u8]) -> String {
+    ///     DispnetHash::new(content).to_ulid(SystemTime::now()).to_string()
+    /// }
+    /// ```
+    #[cfg(feature = "ulid")]
+    pub fn to_ulid(&self, timestamp: SystemTime) -> Ulid {
+        let timestamp_ms = timestamp.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        let key = blake3::derive_key(ULID_CONTEXT, &self.to_bytes());
+        let mut random = [0u8; 16];
+        random[6..].copy_from_slice(&key[..10]);
+        Ulid::from_parts(timestamp_ms, u128::from_be_bytes(random))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ulid")]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[cfg(feature = "uuid")]
+    use uuid::{Variant, Version};
+
+    use crate::{DispnetHash, HashType};
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_from_hash() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let uuid = dispnet_hash.to_uuid();
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid, dispnet_hash.to_uuid());
+        assert_ne!(uuid, DispnetHash::new("other".as_bytes()).to_uuid());
+        assert_ne!(
+            DispnetHash::create(HashType::CRC, "test".as_bytes(), None).to_uuid(),
+            DispnetHash::create(HashType::CrcIeee, "test".as_bytes(), None).to_uuid()
+        );
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn ulid_from_hash() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let timestamp = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let ulid = dispnet_hash.to_ulid(timestamp);
+        assert_eq!(ulid.timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(ulid, dispnet_hash.to_ulid(timestamp));
+        assert_ne!(ulid.random(), DispnetHash::create(HashType::CRC, "test".as_bytes(), None).to_ulid(timestamp).random());
+        let later_ulid = dispnet_hash.to_ulid(timestamp + Duration::from_millis(1));
+        assert_eq!(later_ulid.random(), ulid.random());
+        assert!(later_ulid > ulid);
+    }
+}
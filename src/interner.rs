@@ -0,0 +1,84 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use crate::DispnetHash;
+
+/// Interner which shares equal hashes in one `Arc`, an index which holds the same hashes
+/// many times then only stores every hash once (the interner holds the digest only in the `Arc`).
+///
+/// # Usage
+/// ```
+/// use std::sync::Arc;
+///
+/// use dispnet_hash::{DispnetHash, HashInterner};
+///
+/// fn intern() {
+///     let interner = HashInterner::default();
+///     let first_hash = interner.intern(DispnetHash::new("test".as_bytes()));
+///     let second_hash = interner.intern(DispnetHash::new("test".as_bytes()));
+///     assert!(Arc::ptr_eq(&first_hash, &second_hash));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct HashInterner {
+    hashes: Mutex<HashSet<Arc<DispnetHash>>>,
+}
+
+impl HashInterner {
+    /// Shared instance of the hash, the hash is added if it is not interned yet.
+    pub fn intern(&self, hash: DispnetHash) -> Arc<DispnetHash> {
+        let mut hashes = self.hashes.lock().unwrap();
+        if let Some(interned_hash) = hashes.get(&hash) {
+            return interned_hash.clone();
+        }
+        let interned_hash = Arc::new(hash);
+        hashes.insert(interned_hash.clone());
+        interned_hash
+    }
+
+    /// Shared instance of the hash if it is interned.
+    pub fn get(&self, hash: &DispnetHash) -> Option<Arc<DispnetHash>> {
+        self.hashes.lock().unwrap().get(hash).cloned()
+    }
+
+    /// Remove the hashes which are only referenced by the interner, returns the number of removed hashes.
+    pub fn purge_unused(&self) -> usize {
+        let mut hashes = self.hashes.lock().unwrap();
+        let length = hashes.len();
+        hashes.retain(|hash| Arc::strong_count(hash) > 1);
+        length - hashes.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{DispnetHash, HashInterner, HashType};
+
+    #[test]
+    fn intern_hashes() {
+        let interner = HashInterner::default();
+        let blake3_hash = interner.intern(DispnetHash::new("test".as_bytes()));
+        let crc_hash = interner.intern(DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+        assert!(!Arc::ptr_eq(&blake3_hash, &crc_hash));
+        assert!(Arc::ptr_eq(&interner.intern(DispnetHash::new("test".as_bytes())), &blake3_hash));
+        assert!(Arc::ptr_eq(&interner.get(&DispnetHash::new("test".as_bytes())).unwrap(), &blake3_hash));
+        assert!(interner.get(&DispnetHash::new("other".as_bytes())).is_none());
+        assert_eq!(interner.len(), 2);
+
+        drop(crc_hash);
+        assert_eq!(interner.purge_unused(), 1);
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{DispnetHash, HashType};
+
+/// Iterator which lazily hashes every item (see [`DispnetHashIterExt::dispnet_hashes`]).
+#[derive(Debug, Clone)]
+pub struct DispnetHashes<I> {
+    iter: I,
+    hash_type: HashType,
+}
+
+impl<I> Iterator for DispnetHashes<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = (I::Item, DispnetHash);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| {
+            let dispnet_hash = DispnetHash::create(self.hash_type, item.as_ref(), None);
+            (item, dispnet_hash)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Extension trait to hash the items of an iterator.
+pub trait DispnetHashIterExt: Iterator + Sized
+where
+    Self::Item: AsRef<[u8]>,
+{
+    /// Yield every item together with its dispnet hash.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHashIterExt, HashType};
+    ///
+    /// fn index_words() {
+    ///     let index: Vec<_> = "quick brown fox".split(' ').dispnet_hashes(HashType::Blake3).collect();
+    ///     assert_eq!(index.len(), 3);
+    /// }
+    /// ```
+    fn dispnet_hashes(self, hash_type: HashType) -> DispnetHashes<Self> {
+        DispnetHashes {
+            iter: self,
+            hash_type,
+        }
+    }
+}
+
+impl<I> DispnetHashIterExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, DispnetHashIterExt, HashType};
+
+    #[test]
+    fn iterator_hashes() {
+        let hashes: Vec<_> = ["test", "other"].into_iter().dispnet_hashes(HashType::CRC).collect();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes[0].0, "test");
+        assert_eq!(hashes[0].1, DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+        assert_eq!(hashes[1].1, DispnetHash::create(HashType::CRC, "other".as_bytes(), None));
+    }
+}
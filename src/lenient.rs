@@ -0,0 +1,80 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{DispnetHash, HashError};
+
+/// Recoverable issue found by [`DispnetHash::parse_lenient`].
+#[derive(Debug)]
+pub enum ParseWarning {
+    /// Whitespace around the hash was removed.
+    SurroundingWhitespace,
+    /// The hash type is unknown, Blake3 is used as fallback.
+    UnknownHashType { raw_type: String },
+    /// The digest contains upper case hex characters.
+    UppercaseHex,
+    /// The hash could not be parsed at all.
+    Rejected { error: HashError },
+}
+
+impl DispnetHash {
+    /// Parse a hash and collect recoverable issues instead of rejecting the hash.
+    ///
+    /// Surrounding whitespace, upper case hex and unknown hash types (Blake3 fallback) are tolerated.
+    /// If the hash can't be parsed the last warning is [`ParseWarning::Rejected`].
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, ParseWarning};
+    ///
+    /// fn import(raw_hashes: &[&str]) -> Vec<DispnetHash> {
+    ///     raw_hashes
+    ///         .iter()
+    ///         .filter_map(|raw_hash| {
+    ///             let (hash, warnings) = DispnetHash::parse_lenient(raw_hash);
+    ///             for warning in warnings {
+    ///                 eprintln!("{}: {:?}", raw_hash, warning);
+    ///             }
+    ///             hash
+    ///         })
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn parse_lenient(hash_value: &str) -> (Option<DispnetHash>, Vec<ParseWarning>) {
+        let mut warnings = Vec::new();
+        let trimmed_value = hash_value.trim();
+        if trimmed_value.len() != hash_value.len() {
+            warnings.push(ParseWarning::SurroundingWhitespace);
+        }
+        match DispnetHash::parse_reporting(trimmed_value, &mut warnings) {
+            Ok(hash) => (Some(hash), warnings),
+            Err(error) => {
+                warnings.push(ParseWarning::Rejected { error });
+                (None, warnings)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType, ParseWarning};
+
+    #[test]
+    fn parse_lenient() {
+        let (hash, warnings) = DispnetHash::parse_lenient("02001032323538363632303830");
+        assert_eq!(hash.unwrap().hash_type, HashType::CRC);
+        assert!(warnings.is_empty());
+
+        let (hash, warnings) = DispnetHash::parse_lenient(" 0900324878CA0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215\n");
+        assert_eq!(hash.unwrap().to_string(), "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+        assert!(matches!(
+            warnings.as_slice(),
+            [ParseWarning::SurroundingWhitespace, ParseWarning::UnknownHashType { raw_type }, ParseWarning::UppercaseHex] if raw_type == "09"
+        ));
+    }
+
+    #[test]
+    fn parse_lenient_rejected() {
+        let (hash, warnings) = DispnetHash::parse_lenient("0200103232");
+        assert!(hash.is_none());
+        assert!(matches!(warnings.as_slice(), [ParseWarning::Rejected { error: HashError::DigestLengthMissmatch { .. } }]));
+    }
+}
@@ -1,42 +1,621 @@
-use std::{
-    fmt,
-    str::{from_utf8, FromStr},
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use base64::{prelude::BASE64_STANDARD, Engine};
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::{fmt::Write, str::{from_utf8, FromStr}};
+#[cfg(feature = "std")]
+use std::{path::PathBuf, sync::Arc};
+
+#[cfg(feature = "std")]
+mod algorithms;
+#[cfg(feature = "alloc")]
+mod argon2_backend;
+#[cfg(feature = "std")]
+mod argon2_threads;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+mod capabilities;
+#[cfg(feature = "std")]
+mod chunk_manifest;
+#[cfg(feature = "alloc")]
+mod context;
+#[cfg(feature = "archive")]
+mod archive;
+#[cfg(feature = "serde")]
+mod canonical;
+#[cfg(feature = "std")]
+mod dedup;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(feature = "serde")]
+pub mod serde_formats;
+#[cfg(feature = "ed25519")]
+mod signed;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+mod extract;
+#[cfg(feature = "uniffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod engine;
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "tokio")]
+mod file_async;
+#[cfg(feature = "std")]
+mod fingerprint;
+mod fixed;
+#[cfg(feature = "flatbuffers")]
+mod flatbuffer;
+#[cfg(feature = "alloc")]
+mod git;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "std")]
+mod hash_kinds;
+#[cfg(feature = "std")]
+mod hasher;
+#[cfg(feature = "std")]
+mod interner;
+#[cfg(any(feature = "ulid", feature = "uuid"))]
+mod ids;
+#[cfg(feature = "std")]
+mod iter;
+#[cfg(feature = "alloc")]
+mod lenient;
+#[cfg(feature = "alloc")]
+mod limits;
+#[cfg(feature = "std")]
+mod manifest;
+#[cfg(feature = "std")]
+mod meta;
+#[cfg(feature = "std")]
+mod migration;
+#[cfg(feature = "std")]
+mod minhash;
+#[cfg(feature = "std")]
+mod multipart;
+#[cfg(feature = "std")]
+mod namespace;
+#[cfg(feature = "std")]
+mod negotiate;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "password-hash")]
+mod password;
+#[cfg(feature = "alloc")]
+mod phc;
+#[cfg(feature = "alloc")]
+mod policy;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "prost")]
+mod proto;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "notify")]
+mod watch;
+#[cfg(all(unix, feature = "xattr"))]
+mod xattr_hash;
+#[cfg(feature = "std")]
+mod rolling;
+#[cfg(feature = "std")]
+mod rsync;
+#[cfg(feature = "std")]
+mod self_test;
+#[cfg(feature = "alloc")]
+mod simhash;
+#[cfg(feature = "alloc")]
+mod sort_key;
+#[cfg(feature = "std")]
+mod tee;
+#[cfg(feature = "std")]
+mod timestamped;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+mod uring;
+#[cfg(feature = "alloc")]
+mod validate;
+#[cfg(feature = "std")]
+mod vectors;
+#[cfg(feature = "std")]
+mod verified;
+
+#[cfg(feature = "archive")]
+pub use archive::{hash_tar, hash_zip};
+#[cfg(feature = "std")]
+pub use algorithms::{Argon2Hash, Blake3Hash, Crc32Hash};
+#[cfg(feature = "std")]
+pub use argon2_threads::Argon2ThreadPool;
+#[cfg(feature = "ed25519")]
+pub use signed::SignedDispnetHash;
+#[cfg(feature = "notify")]
+pub use watch::{HashChange, HashWatcher};
+#[cfg(all(unix, feature = "xattr"))]
+pub use xattr_hash::{read_hash_xattr, store_hash_xattr, verify_xattr, HASH_XATTR_NAME};
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub use extract::{HashPath, HashQuery, HashRejection};
+#[cfg(feature = "std")]
+pub use cache::CachedHasher;
+#[cfg(feature = "std")]
+pub use capabilities::{capabilities, select_fastest, Capabilities};
+#[cfg(feature = "std")]
+pub use chunk_manifest::{ChunkManifest, ManifestChunk};
+#[cfg(feature = "tokio-util")]
+pub use codec::HashFramedCodec;
+#[cfg(feature = "std")]
+pub use dedup::{chunk_ranges, ChunkRef, DedupEntry, DedupIndex};
+#[cfg(feature = "std")]
+pub use engine::{DefaultEngine, HashEngine};
+pub use fixed::{DispnetHashFixed, FixedHashError, TypedDigest};
+#[cfg(feature = "flatbuffers")]
+pub use flatbuffer::{pack_flatbuffer, unpack_flatbuffer, unpack_flatbuffer_table, DispnetHashTable};
+#[cfg(feature = "std")]
+pub use file::{hash_file, hash_file_resumable, hash_file_with, FileHashOptions};
+#[cfg(feature = "tokio")]
+pub use file_async::hash_file_async;
+#[cfg(feature = "alloc")]
+pub use git::GitObjectKind;
+#[cfg(feature = "std")]
+pub use hash_kinds::{ContentHash, PasswordHash};
+#[cfg(feature = "std")]
+pub use hasher::DispnetHasher;
+#[cfg(feature = "std")]
+pub use interner::HashInterner;
+#[cfg(feature = "std")]
+pub use iter::{DispnetHashIterExt, DispnetHashes};
+#[cfg(feature = "alloc")]
+pub use lenient::ParseWarning;
+#[cfg(feature = "alloc")]
+pub use limits::{ParseLimits, ParseOptions};
+#[cfg(feature = "std")]
+pub use manifest::{Manifest, ManifestDiff};
+#[cfg(feature = "std")]
+pub use meta::DispnetHashMeta;
+#[cfg(feature = "std")]
+pub use migration::{LazyMigration, MigrationRecord};
+#[cfg(feature = "std")]
+pub use minhash::{MinHash, MinHashSignature};
+#[cfg(feature = "std")]
+pub use multipart::CombineMode;
+#[cfg(feature = "std")]
+pub use namespace::NamespacedHasher;
+#[cfg(feature = "std")]
+pub use negotiate::{decode_preferences, encode_preferences, negotiate};
+#[cfg(feature = "password-hash")]
+pub use password::DispnetPasswordHasher;
+#[cfg(feature = "alloc")]
+pub use phc::Argon2Params;
+#[cfg(feature = "alloc")]
+pub use policy::{HashPolicy, PolicyViolation};
+#[cfg(feature = "std")]
+pub use pool::HashBufferPool;
+#[cfg(feature = "prost")]
+pub use proto::DispnetHashProto;
+#[cfg(feature = "std")]
+pub use registry::IdRegistry;
+#[cfg(feature = "std")]
+pub use rolling::RollingHash;
+#[cfg(feature = "std")]
+pub use rsync::{delta, signature, BlockSignature, Delta, DeltaOperation, Signature};
+#[cfg(feature = "std")]
+pub use self_test::{self_test, SelfTestReport, SelfTestResult};
+#[cfg(feature = "alloc")]
+pub use simhash::simhash;
+#[cfg(feature = "std")]
+pub use tee::copy_and_hash;
+#[cfg(feature = "std")]
+pub use timestamped::TimestampedDispnetHash;
+#[cfg(feature = "std")]
+pub use vectors::{test_vectors, test_vectors_json, TestVector};
+#[cfg(feature = "std")]
+pub use verified::VerifiedReader;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+#[cfg(feature = "alloc")]
+const DEFAULT_SALT: &[u8] = b"A8nUz1Pkc0IZ0uJSZNnMlvdLz0T3al5Hjhg2";
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const INVALID_HEX: u8 = 0xff;
+/// Nibble value of every ASCII character (upper and lower case), `INVALID_HEX` for non hex characters.
+const HEX_VALUES: [u8; 256] = {
+    let mut values = [INVALID_HEX; 256];
+    let mut i = 0;
+    while i < 10 {
+        values[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        values[b'a' as usize + i] = 10 + i as u8;
+        values[b'A' as usize + i] = 10 + i as u8;
+        i += 1;
+    }
+    values
 };
 
+/// Check the stored digest against the hash type: the raw digest length for fixed size types,
+/// a canonical decimal u32 for CRC and a PHC string for Argon2.
+fn digest_fits_type(hash_type: HashType, mut digest: impl ExactSizeIterator<Item = u8>) -> bool {
+    match hash_type {
+        HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => {
+            let length = digest.len();
+            let mut checksum: u64 = 0;
+            let mut first_digit = None;
+            for digit in digest.by_ref() {
+                if !digit.is_ascii_digit() {
+                    return false;
+                }
+                first_digit.get_or_insert(digit);
+                checksum = checksum * 10 + (digit - b'0') as u64;
+            }
+            (1..=10).contains(&length) && (length == 1 || first_digit != Some(b'0')) && checksum <= u32::MAX as u64
+        }
+        HashType::Argon2 => digest.take(7).eq(b"$argon2".iter().copied()),
+        _ => hash_type.digest_len() == Some(digest.len()),
+    }
+}
+
+/// Append the bytes as lower case hex to the string.
+#[cfg(feature = "alloc")]
+fn push_hex(bytes: &[u8], hex: &mut String) {
+    hex.reserve(2 * bytes.len());
+    // Writing to a String never fails.
+    let _ = write_hex(bytes, hex);
+}
+
+/// Write the bytes as lower case hex without allocating, 8 bytes at once (see [`hex_u64`]).
+#[cfg(feature = "alloc")]
+fn write_hex(bytes: &[u8], out: &mut impl Write) -> fmt::Result {
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in chunks.by_ref() {
+        let digits = hex_u64(u64::from_be_bytes(chunk.try_into().unwrap())).to_be_bytes();
+        // Only ASCII hex digits.
+        out.write_str(from_utf8(&digits).unwrap())?;
+    }
+    for byte in chunks.remainder() {
+        out.write_char(HEX_DIGITS[(byte >> 4) as usize] as char)?;
+        out.write_char(HEX_DIGITS[(byte & 0x0f) as usize] as char)?;
+    }
+    Ok(())
+}
+
+/// Hex digits of 8 bytes in one 128 bit register (one nibble per byte, then mapped to ASCII).
+#[cfg(feature = "alloc")]
+fn hex_u64(value: u64) -> u128 {
+    const LOW_NIBBLES: u64 = 0x0f0f_0f0f_0f0f_0f0f;
+    const ONES: u128 = 0x0101_0101_0101_0101_0101_0101_0101_0101;
+    let spread = |value: u64| {
+        let mut value = value as u128;
+        value = (value | (value << 32)) & 0x0000_0000_ffff_ffff_0000_0000_ffff_ffff;
+        value = (value | (value << 16)) & 0x0000_ffff_0000_ffff_0000_ffff_0000_ffff;
+        (value | (value << 8)) & 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff
+    };
+    let nibbles = (spread((value >> 4) & LOW_NIBBLES) << 8) | spread(value & LOW_NIBBLES);
+    // 1 for every nibble above 9, those are shifted from ':' to 'a'.
+    let letters = ((nibbles + 6 * ONES) >> 4) & ONES;
+    nibbles + b'0' as u128 * ONES + (b'a' - b'0' - 10) as u128 * letters
+}
+
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum HashError {
     Undefined,
     InvalidDigest { hex_digest: String },
     DigestLength { raw_digest_length: String },
     DigestLengthMissmatch { length: usize, digest: Vec<u8> },
+    InvalidSignature { hex_signature: String },
+    InvalidTimestamp { raw_timestamp: String },
+    InvalidMetadata { raw_metadata: String },
+    UnknownHashType { type_code: u8 },
+    InvalidHasherState { state_length: usize },
+    InvalidDigestForType { hash_type: HashType, length: usize },
+    Serialization { message: String },
+    InvalidManifest { line: String },
+    InvalidPreferences { raw_preferences: String },
+    PolicyViolation { violation: PolicyViolation },
+    ContentMismatch { hash: String },
+    HandleCollision { handle: u64, existing_hash: String, hash: String },
+    BufferTooSmall { required: usize, available: usize },
+    InputTooLong { length: usize, max_length: usize },
+    DigestTooLong { length: usize, max_length: usize },
+    InvalidFingerprint { fingerprint: String },
+    UnexpectedHashType { hash_type: HashType },
+    SaltTooShort { length: usize, min_length: usize },
+    DisallowedHashType { hash_type: HashType },
 }
 
-#[derive(Debug)]
+/// Options of [`DispnetHash::create`], built with the `with_*` methods (new options don't break callers).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{CrcVariant, DispnetHash, HashConfig, HashType};
+///
+/// fn configured_hashes() {
+///     let argon2_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678").with_argon2_lanes(2)));
+///     assert_eq!(argon2_hash.argon2_params().unwrap().lanes, 2);
+///     let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), Some(HashConfig::default().with_crc_variant(CrcVariant::Ieee)));
+///     assert_eq!(crc_hash.hash_type, HashType::CrcIeee);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+#[non_exhaustive]
 pub struct HashConfig {
     pub salt: Option<Box<Vec<u8>>>,
+    /// CRC-32 polynomial used for `HashType::CRC`, Castagnoli if not set (see [`CrcVariant`]).
+    pub crc_variant: Option<CrcVariant>,
+    /// Parallelism (lanes) of Argon2, 1 if not set. Clamped to 1..=512 (Argon2 needs 8 KiB of the 4096 KiB per lane).
+    pub argon2_lanes: Option<u32>,
+    /// Limits the threads used for the Argon2 lanes (see [`Argon2ThreadPool`]).
+    #[cfg(feature = "std")]
+    pub argon2_thread_pool: Option<Arc<Argon2ThreadPool>>,
+    /// Domain separation: the same value hashed with different contexts has different digests.
+    /// Blake3 derives a key from the context, all other types prepend the context with its length.
+    /// Verify with [`DispnetHash::verify_in_context`].
+    pub context: Option<String>,
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "alloc")]
+impl HashConfig {
+    /// Salt of Argon2, a fixed default salt is used if not set.
+    pub fn with_salt(mut self, salt: impl Into<Vec<u8>>) -> Self {
+        self.salt = Some(Box::new(salt.into()));
+        self
+    }
+
+    pub fn with_crc_variant(mut self, crc_variant: CrcVariant) -> Self {
+        self.crc_variant = Some(crc_variant);
+        self
+    }
+
+    pub fn with_argon2_lanes(mut self, argon2_lanes: u32) -> Self {
+        self.argon2_lanes = Some(argon2_lanes);
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub fn with_argon2_thread_pool(mut self, argon2_thread_pool: Arc<Argon2ThreadPool>) -> Self {
+        self.argon2_thread_pool = Some(argon2_thread_pool);
+        self
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum HashType {
     Blake3,
     CRC,
     Argon2,
+    SimHash,
+    GitSha1,
+    CrcIeee,
+    CrcKoopman,
 }
 
-impl fmt::Display for HashType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// CRC-32 polynomial of a CRC hash.
+///
+/// Each variant has its own hash type code, the variant is recorded in the hash
+/// and parse/verify use the matching polynomial.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{CrcVariant, DispnetHash, HashConfig, HashType};
+///
+/// fn zip_checksum() {
+///     let config = HashConfig::default().with_crc_variant(CrcVariant::Ieee);
+///     let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), Some(config));
+///     assert_eq!(dispnet_hash.hash_type, HashType::CrcIeee);
+///     assert_eq!(dispnet_hash.to_string(), "06001033363332323333393936");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CrcVariant {
+    /// CRC-32C (iSCSI, ext4, Btrfs), the default of `HashType::CRC`.
+    Castagnoli,
+    /// CRC-32 (ISO-HDLC) used by zip, gzip and PNG.
+    Ieee,
+    /// CRC-32K (Koopman).
+    Koopman,
+}
+
+/// CRC-32K is not part of the CRC catalogue.
+#[cfg(feature = "alloc")]
+const CRC_32_KOOPMAN: crc::Algorithm<u32> = crc::Algorithm {
+    width: 32,
+    poly: 0x741b8cd7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0x2d3dd0ae,
+    residue: 0x0843323b,
+};
+
+impl CrcVariant {
+    /// Hash type which records the variant.
+    pub fn hash_type(&self) -> HashType {
         match *self {
-            HashType::Argon2 => {
-                write!(f, "{:02}", 3)
-            }
-            HashType::CRC => {
-                write!(f, "{:02}", 2)
-            }
-            _ => {
-                write!(f, "{:02}", 1)
-            }
+            CrcVariant::Castagnoli => HashType::CRC,
+            CrcVariant::Ieee => HashType::CrcIeee,
+            CrcVariant::Koopman => HashType::CrcKoopman,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn algorithm(&self) -> &'static crc::Algorithm<u32> {
+        match *self {
+            CrcVariant::Castagnoli => &crc::CRC_32_ISCSI,
+            CrcVariant::Ieee => &crc::CRC_32_ISO_HDLC,
+            CrcVariant::Koopman => &CRC_32_KOOPMAN,
+        }
+    }
+}
+
+/// Security guarantee a hash type gives for the hashed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SecurityClass {
+    /// Similar values produce similar hashes, only useful to find near duplicates.
+    Fingerprint,
+    /// Detects accidental changes but collisions can be created on purpose.
+    Checksum,
+    /// Collision resistant, usable as a content-integrity proof.
+    Cryptographic,
+    /// Salted and slow, meant to store passwords.
+    PasswordHash,
+}
+
+/// Intended use of a hash, see [`HashType::for_purpose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Purpose {
+    /// Prove that content was not changed, also against attackers.
+    ContentIntegrity,
+    /// Store passwords (or other low entropy secrets).
+    PasswordStorage,
+    /// Detect accidental corruption as fast as possible.
+    FastChecksum,
+}
+
+impl HashType {
+    /// Recommended hash type for the purpose.
+    ///
+    /// * `ContentIntegrity`: Blake3.
+    /// * `PasswordStorage`: Argon2.
+    /// * `FastChecksum`: CRC (CRC-32C is hardware accelerated on most CPUs).
+    ///
+    /// The recommendation can change in a future version, stored hashes keep their type.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType, Purpose};
+    ///
+    /// fn block_hash(block: &[u8]) -> DispnetHash {
+    ///     DispnetHash::create(HashType::for_purpose(Purpose::ContentIntegrity), block, None)
+    /// }
+    /// ```
+    pub const fn for_purpose(purpose: Purpose) -> HashType {
+        match purpose {
+            Purpose::ContentIntegrity => HashType::Blake3,
+            Purpose::PasswordStorage => HashType::Argon2,
+            Purpose::FastChecksum => HashType::CRC,
+        }
+    }
+
+    /// Security class of the hash type.
+    /// Git SHA-1 is a checksum because SHA-1 collisions can be created on purpose.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{HashType, SecurityClass};
+    ///
+    /// fn is_integrity_proof(hash_type: HashType) -> bool {
+    ///     hash_type.security_class() == SecurityClass::Cryptographic
+    /// }
+    /// ```
+    pub fn security_class(&self) -> SecurityClass {
+        match *self {
+            HashType::Blake3 => SecurityClass::Cryptographic,
+            HashType::Argon2 => SecurityClass::PasswordHash,
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman | HashType::GitSha1 => SecurityClass::Checksum,
+            HashType::SimHash => SecurityClass::Fingerprint,
+        }
+    }
+
+    /// Length of the raw digest in bytes (see [`DispnetHash::from_raw_digest`]).
+    /// `None` for Argon2, its PHC string has a variable length.
+    /// CRC is stored as decimal string in the dispnet hash, its raw checksum has 4 bytes.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::HashType;
+    ///
+    /// fn digest_buffer(hash_type: HashType) -> Vec<u8> {
+    ///     Vec::with_capacity(hash_type.digest_len().unwrap_or(128))
+    /// }
+    /// ```
+    pub const fn digest_len(&self) -> Option<usize> {
+        match *self {
+            HashType::Blake3 => Some(32),
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => Some(4),
+            HashType::Argon2 => None,
+            HashType::SimHash => Some(8),
+            HashType::GitSha1 => Some(20),
+        }
+    }
+
+    /// CRC-32 variant of the CRC hash types, `None` for all other types.
+    pub fn crc_variant(&self) -> Option<CrcVariant> {
+        match *self {
+            HashType::CRC => Some(CrcVariant::Castagnoli),
+            HashType::CrcIeee => Some(CrcVariant::Ieee),
+            HashType::CrcKoopman => Some(CrcVariant::Koopman),
+            _ => None,
+        }
+    }
+
+    /// Hash type after applying the config, `HashType::CRC` becomes the type of the configured CRC variant.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn configured(self, config: Option<&HashConfig>) -> HashType {
+        match (self, config.and_then(|hash_config| hash_config.crc_variant)) {
+            (HashType::CRC, Some(crc_variant)) => crc_variant.hash_type(),
+            _ => self,
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match *self {
+            HashType::CrcKoopman => 7,
+            HashType::CrcIeee => 6,
+            HashType::GitSha1 => 5,
+            HashType::SimHash => 4,
+            HashType::Argon2 => 3,
+            HashType::CRC => 2,
+            HashType::Blake3 => 1,
+        }
+    }
+
+    fn from_code(code: u8) -> Option<HashType> {
+        match code {
+            7 => Some(HashType::CrcKoopman),
+            6 => Some(HashType::CrcIeee),
+            5 => Some(HashType::GitSha1),
+            4 => Some(HashType::SimHash),
+            3 => Some(HashType::Argon2),
+            2 => Some(HashType::CRC),
+            1 => Some(HashType::Blake3),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for HashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.code())
+    }
 }
 
 /// Dispnet hash is as self descriping hash format.
@@ -55,7 +634,8 @@ impl fmt::Display for HashType {
 ///     assert_eq!(display_hash, "010324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
 /// }
 /// ```
-#[derive(Debug)]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
 pub struct DispnetHash {
     pub hash_type: HashType,
     pub digest_length: usize,
@@ -64,13 +644,10 @@ pub struct DispnetHash {
     value: String,
 }
 
-trait Hash {
-    fn equal(hash: DispnetHash) -> bool;
-    fn upgrade();
-}
-
+#[cfg(feature = "alloc")]
 impl DispnetHash {
     /// Create a hash with the default typ (Blake3).
+    #[must_use]
     pub fn new(value: &[u8]) -> Self {
         DispnetHash::create(HashType::Blake3, value, None)
     }
@@ -85,24 +662,18 @@ impl DispnetHash {
     ///     let dispnet_hash_Balke3 = DispnetHash::create(HashType::Blake3, "test".as_bytes(), None);
     ///     let dispnet_hash_CRC = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
     ///     let dispnet_hash_Argon2 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
-    ///     let dispnet_hash_Argon2_slat = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
+    ///     let dispnet_hash_Argon2_slat = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
     /// }
     /// ```
+    #[must_use]
     pub fn create(hash_type: HashType, value: &[u8], config: Option<HashConfig>) -> Self {
-        let internal_hash = InternalDispnetHash::new(hash_type, value, config);
-        let internal_hash_value = format!("{}", internal_hash);
-        let encoded: u64 = DispnetHash::encoded_u64(&internal_hash.digest_value);
-        Self {
-            hash_type: internal_hash.hash_type,
-            digest_length: internal_hash.digest_length,
-            digest_value: internal_hash.digest_value,
-            digest_encoded: encoded,
-            value: internal_hash_value,
-        }
+        let hash_type = hash_type.configured(config.as_ref());
+        let mut digest_value = Vec::new();
+        DispnetHash::digest_into(hash_type, value, config.as_ref(), &mut digest_value);
+        DispnetHash::from_digest(hash_type, digest_value)
     }
 
-    /// Verify a dispnet hash string with raw value.
-    /// The hash must be created with the Argon2 type
+    /// Verify a dispnet hash string with raw value (see [`DispnetHash::verify_instance`]).
     /// # Usage
     /// ```
     /// use dispnet_hash::{DispnetHash, HashType};
@@ -110,9 +681,10 @@ impl DispnetHash {
     /// fn verify_hash() {
     ///     let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
     ///     
-    ///     DispnetHash::verify(&dispnet_hash.to_string(), "test".as_bytes());
+    ///     assert!(DispnetHash::verify(&dispnet_hash.to_string(), "test".as_bytes()));
     /// }
     /// ```
+    #[must_use]
     pub fn verify(hash: &str, value: &[u8]) -> bool {
         let dispnet_hash = hash.parse::<DispnetHash>();
         if let Ok(hash) = dispnet_hash {
@@ -121,8 +693,28 @@ impl DispnetHash {
         false
     }
 
+    /// Verify a dispnet hash string with raw value if its hash type is allowed.
+    ///
+    /// The hash type is chosen by whoever created the hash string, without an allow-list an attacker
+    /// can replace a Blake3 hash with a CRC whose collisions are easy to create.
+    /// Returns `HashError::DisallowedHashType` for other types and the parse error for invalid hashes.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashError, HashType};
+    ///
+    /// fn verify_download(raw_hash: &str, content: &[u8]) -> Result<bool, HashError> {
+    ///     DispnetHash::verify_allowed(raw_hash, content, &[HashType::Blake3])
+    /// }
+    /// ```
+    pub fn verify_allowed(hash: &str, value: &[u8], allowed_types: &[HashType]) -> Result<bool, HashError> {
+        let hash = hash.parse::<DispnetHash>()?;
+        hash.check_allowed_type(Some(allowed_types))?;
+        Ok(DispnetHash::verify_instance(&hash, value))
+    }
+
     /// Verify a dispnet hash instance with raw value.
-    /// The hash must be created with the Argon2 type
+    /// Argon2 hashes are verified with the salt and parameters encoded in the hash,
+    /// all other types by hashing the value again.
     /// # Usage
     /// ```
     /// use dispnet_hash::{DispnetHash, HashType};
@@ -130,32 +722,391 @@ impl DispnetHash {
     /// fn verify_hash_instance() {
     ///     let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
     ///     
-    ///     DispnetHash::verify_instance(&dispnet_hash, "test".as_bytes());
+    ///     assert!(DispnetHash::verify_instance(&dispnet_hash, "test".as_bytes()));
     /// }
     /// ```
+    #[must_use]
     pub fn verify_instance(hash: &DispnetHash, value: &[u8]) -> bool {
-        let str_hash = from_utf8(&hash.digest_value).unwrap();
-        let matches_result = argon2::verify_encoded(str_hash, value);
-        if let Ok(matches) = matches_result {
-            return matches;
+        if hash.hash_type != HashType::Argon2 {
+            return DispnetHash::create(hash.hash_type, value, None) == *hash;
         }
-        false
+        from_utf8(&hash.digest_value).is_ok_and(|str_hash| argon2_backend::verify_encoded(str_hash, value))
     }
 
     fn parse(hash_value: &str) -> Result<Self, HashError> {
-        let internal_hash_result = InternalDispnetHash::parse(hash_value);
-        if let Ok(internal_hash) = internal_hash_result {
-            let internal_hash_value = format!("{}", internal_hash);
-            let encoded: u64 = DispnetHash::encoded_u64(&internal_hash.digest_value);
-            return Ok(Self {
-                hash_type: internal_hash.hash_type,
-                digest_length: internal_hash.digest_length,
-                digest_value: internal_hash.digest_value,
-                digest_encoded: encoded,
-                value: internal_hash_value,
+        DispnetHash::parse_reporting(hash_value, &mut Vec::new())
+    }
+
+    /// Parse the display format and report recoverable issues as warnings.
+    fn parse_reporting(hash_value: &str, warnings: &mut Vec<ParseWarning>) -> Result<Self, HashError> {
+        let (Some(raw_type), Some(raw_digest_len), Some(raw_digest_value)) =
+            (hash_value.get(..2), hash_value.get(2..6), hash_value.get(6..))
+        else {
+            return Err(HashError::DigestLength {
+                raw_digest_length: hash_value.to_owned(),
+            });
+        };
+        // Only ASCII digits, `str::parse` would accept a sign.
+        let hash_type = match fixed::parse_decimal(raw_type.as_bytes()).and_then(|type_code| HashType::from_code(type_code as u8)) {
+            Some(hash_type) => hash_type,
+            None => {
+                warnings.push(ParseWarning::UnknownHashType {
+                    raw_type: raw_type.to_owned(),
+                });
+                HashType::Blake3
+            }
+        };
+        let Some(hash_bytes) = DispnetHash::hex_to_bytes(raw_digest_value) else {
+            return Err(HashError::InvalidDigest {
+                hex_digest: raw_digest_value.to_owned(),
+            });
+        };
+        let Some(hash_bytes_len) = fixed::parse_decimal(raw_digest_len.as_bytes()) else {
+            return Err(HashError::DigestLength {
+                raw_digest_length: raw_digest_len.to_owned(),
+            });
+        };
+        if hash_bytes_len != hash_bytes.len() {
+            return Err(HashError::DigestLengthMissmatch {
+                length: hash_bytes_len,
+                digest: hash_bytes,
+            });
+        }
+        if !digest_fits_type(hash_type, hash_bytes.iter().copied()) {
+            return Err(HashError::InvalidDigestForType {
+                hash_type,
+                length: hash_bytes_len,
+            });
+        }
+        if raw_digest_value.bytes().any(|b| b.is_ascii_uppercase()) {
+            warnings.push(ParseWarning::UppercaseHex);
+        }
+        Ok(DispnetHash::from_digest(hash_type, hash_bytes))
+    }
+
+    /// Compare two hashes by hash type and digest bytes, independent of their string representation.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn eq_canonical() {
+    ///     let lower = "02001032323538363632303830".parse::<DispnetHash>().unwrap();
+    ///     let upper = "02001032323538363632303830".to_uppercase().parse::<DispnetHash>().unwrap();
+    ///     assert!(lower.eq_canonical(&upper));
+    /// }
+    /// ```
+    pub fn eq_canonical(&self, other: &DispnetHash) -> bool {
+        self.hash_type == other.hash_type && self.digest_value == other.digest_value
+    }
+
+    /// Parse a hash string and return its canonical form (lowercase hex digest),
+    /// surrounding whitespace is ignored.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn normalize() {
+    ///     let canonical_hash = DispnetHash::normalize(" 0100324878CA0425C739FA427F7EDA20FE845F6B2E46BA5FE2A14DF5B1E32F50603215\n").unwrap();
+    ///     assert_eq!(canonical_hash, "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+    /// }
+    /// ```
+    pub fn normalize(input: &str) -> Result<String, HashError> {
+        DispnetHash::parse(input.trim()).map(|hash| hash.value)
+    }
+
+    /// Build the hash from its type and digest, the display value is written in the same pass.
+    fn from_digest(hash_type: HashType, digest_value: Vec<u8>) -> Self {
+        let mut value = String::with_capacity(6 + 2 * digest_value.len());
+        DispnetHash::write_value(hash_type, &digest_value, &mut value);
+        Self {
+            hash_type,
+            digest_length: digest_value.len(),
+            digest_encoded: DispnetHash::encoded_u64(&digest_value),
+            digest_value,
+            value,
+        }
+    }
+
+    /// Create a hash from a decoded digest, the digest must fit the hash type (see [`digest_fits_type`]).
+    fn from_decoded_digest(hash_type: HashType, digest_value: Vec<u8>) -> Result<Self, HashError> {
+        if digest_value.len() > 9999 {
+            return Err(HashError::DigestLength {
+                raw_digest_length: digest_value.len().to_string(),
+            });
+        }
+        if !digest_fits_type(hash_type, digest_value.iter().copied()) {
+            return Err(HashError::InvalidDigestForType {
+                hash_type,
+                length: digest_value.len(),
+            });
+        }
+        Ok(DispnetHash::from_digest(hash_type, digest_value))
+    }
+
+    fn write_value(hash_type: HashType, digest_value: &[u8], value: &mut String) {
+        value.reserve(6 + 2 * digest_value.len());
+        // Writing to a String never fails.
+        let _ = DispnetHash::write_parts(hash_type, digest_value, value);
+    }
+
+    fn write_parts(hash_type: HashType, digest_value: &[u8], out: &mut impl Write) -> fmt::Result {
+        write!(out, "{}{:04}", hash_type, digest_value.len())?;
+        write_hex(digest_value, out)
+    }
+
+    /// Write the display format into an existing buffer without allocating.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn log_line(dispnet_hash: &DispnetHash, line: &mut String) {
+    ///     line.clear();
+    ///     line.push_str("hash=");
+    ///     dispnet_hash.write_to(line).unwrap();
+    /// }
+    /// ```
+    pub fn write_to(&self, out: &mut impl Write) -> fmt::Result {
+        DispnetHash::write_parts(self.hash_type, &self.digest_value, out)
+    }
+
+    fn salt(config: Option<&HashConfig>) -> &[u8] {
+        config
+            .and_then(|hash_config| hash_config.salt.as_deref())
+            .map_or(DEFAULT_SALT, |salt| salt.as_slice())
+    }
+
+    /// Append the digest of the value to the buffer.
+    fn digest_into(hash_type: HashType, value: &[u8], config: Option<&HashConfig>, digest_value: &mut Vec<u8>) {
+        let context = config.and_then(|hash_config| hash_config.context.as_deref());
+        let framed_value;
+        let value = match context {
+            Some(context) if hash_type != HashType::Blake3 => {
+                framed_value = [context::context_prefix(context).as_slice(), value].concat();
+                framed_value.as_slice()
+            }
+            _ => value,
+        };
+        match hash_type {
+            HashType::Argon2 => {
+                let lanes = config.and_then(|hash_config| hash_config.argon2_lanes).unwrap_or(1);
+                #[cfg(feature = "std")]
+                let thread_pool = config.and_then(|hash_config| hash_config.argon2_thread_pool.as_deref());
+                #[cfg(not(feature = "std"))]
+                let thread_pool = None;
+                let hash = argon2_backend::hash_encoded(value, DispnetHash::salt(config), lanes, thread_pool);
+                digest_value.extend_from_slice(hash.as_bytes());
+            }
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => {
+                let crc32 = crc::Crc::<u32>::new(hash_type.crc_variant().unwrap().algorithm());
+                let checksum = crc32.checksum(value);
+                // Decimal digits without the temporary String of `to_string`.
+                let start = digest_value.len();
+                let mut rest = checksum;
+                loop {
+                    digest_value.push(b'0' + (rest % 10) as u8);
+                    rest /= 10;
+                    if rest == 0 {
+                        break;
+                    }
+                }
+                digest_value[start..].reverse();
+            }
+            HashType::SimHash => {
+                let tokens = value
+                    .split(|b| b.is_ascii_whitespace())
+                    .filter(|token| !token.is_empty());
+                digest_value.extend_from_slice(&simhash::fingerprint(tokens).to_le_bytes());
+            }
+            HashType::GitSha1 => {
+                digest_value.extend_from_slice(&git::object_digest(GitObjectKind::Blob, value));
+            }
+            HashType::Blake3 => {
+                let mut hasher = context.map_or_else(blake3::Hasher::new, blake3::Hasher::new_derive_key);
+                #[cfg(feature = "rayon")]
+                if value.len() >= capabilities::BLAKE3_PARALLEL_MIN_LEN && capabilities().blake3_threads > 1 {
+                    digest_value.extend_from_slice(hasher.update_rayon(value).finalize().as_bytes());
+                    return;
+                }
+                digest_value.extend_from_slice(hasher.update(value).finalize().as_bytes());
+            }
+        }
+    }
+
+    /// Convert the hash to its compact binary format.
+    ///
+    /// The binary format is structured as followed:
+    ///
+    /// * First byte is the hash type.
+    /// * Then come 2 bytes (big-endian) which are the length of the bytes from the digest.
+    /// * Digest value as raw bytes.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn to_bytes() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     let bytes = dispnet_hash.to_bytes();
+    ///     assert_eq!(bytes.len(), 35);
+    ///     assert_eq!(DispnetHash::from_bytes(&bytes).unwrap(), dispnet_hash);
+    /// }
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(3 + self.digest_value.len());
+        bytes.push(self.hash_type.code());
+        bytes.extend_from_slice(&(self.digest_length as u16).to_be_bytes());
+        bytes.extend_from_slice(&self.digest_value);
+        bytes
+    }
+
+    /// Create a hash from its compact binary format (see [`DispnetHash::to_bytes`]).
+    /// Trailing bytes after the digest are rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HashError> {
+        if bytes.len() < 3 {
+            return Err(HashError::DigestLength {
+                raw_digest_length: DispnetHash::bytes_to_hex(bytes),
             });
         }
-        Err(internal_hash_result.err().unwrap())
+        let hash_type = HashType::from_code(bytes[0])
+            .ok_or(HashError::UnknownHashType { type_code: bytes[0] })?;
+        let digest_length = u16::from_be_bytes([bytes[1], bytes[2]]) as usize;
+        let digest_value = &bytes[3..];
+        if digest_value.len() != digest_length {
+            return Err(HashError::DigestLengthMissmatch {
+                length: digest_length,
+                digest: digest_value.to_vec(),
+            });
+        }
+        DispnetHash::from_decoded_digest(hash_type, digest_value.to_vec())
+    }
+
+    /// Digest as hex without the dispnet type and length header.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn raw_hex() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     assert_eq!(dispnet_hash.raw_hex(), "4878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+    /// }
+    /// ```
+    pub fn raw_hex(&self) -> String {
+        DispnetHash::bytes_to_hex(&self.digest_value)
+    }
+
+    /// Digest as standard base64 (with padding) without the dispnet type and length header.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn raw_base64() {
+    ///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+    ///     assert_eq!(dispnet_hash.raw_base64(), "SHjKBCXHOfpCf37aIP6EX2suRrpf4qFN9bHjL1BgMhU=");
+    /// }
+    /// ```
+    pub fn raw_base64(&self) -> String {
+        BASE64_STANDARD.encode(&self.digest_value)
+    }
+
+    /// Hash with an all-zero digest of the hash type, used as sentinel value (e.g. in indices and protocols).
+    /// CRC hashes have the checksum 0, `None` for Argon2 which has no fixed digest length.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn nil() {
+    ///     let nil_hash = DispnetHash::nil(HashType::SimHash).unwrap();
+    ///     assert_eq!(nil_hash.to_string(), "0400080000000000000000");
+    ///     assert!(nil_hash.is_nil());
+    /// }
+    /// ```
+    pub fn nil(hash_type: HashType) -> Option<Self> {
+        if hash_type.crc_variant().is_some() {
+            return Some(DispnetHash::from_digest(hash_type, b"0".to_vec()));
+        }
+        let digest_len = hash_type.digest_len()?;
+        Some(DispnetHash::from_digest(hash_type, vec![0; digest_len]))
+    }
+
+    /// True if the hash is the nil hash of its type (see [`DispnetHash::nil`]).
+    pub fn is_nil(&self) -> bool {
+        if self.hash_type.crc_variant().is_some() {
+            return self.crc_value() == Some(0);
+        }
+        self.hash_type.digest_len() == Some(self.digest_value.len()) && self.digest_value.iter().all(|byte| *byte == 0)
+    }
+
+    /// Checksum of a CRC hash (any [`CrcVariant`]), `None` for all other hash types.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn crc_value() {
+    ///     let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+    ///     assert_eq!(dispnet_hash.crc_value(), Some(2258662080));
+    /// }
+    /// ```
+    pub fn crc_value(&self) -> Option<u32> {
+        self.hash_type.crc_variant()?;
+        from_utf8(&self.digest_value).ok()?.parse().ok()
+    }
+
+    /// Wrap a digest computed elsewhere without hashing the data again.
+    ///
+    /// The digest must be in the raw output format of the algorithm:
+    /// 32 bytes for Blake3, 4 bytes (big-endian) for CRC, the PHC string for Argon2,
+    /// 8 bytes (little-endian) for SimHash and 20 bytes for Git SHA-1.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn from_raw_digest() {
+    ///     let dispnet_hash = DispnetHash::from_raw_digest(HashType::CRC, &2258662080u32.to_be_bytes()).unwrap();
+    ///     assert_eq!(dispnet_hash.to_string(), "02001032323538363632303830");
+    /// }
+    /// ```
+    pub fn from_raw_digest(hash_type: HashType, digest: &[u8]) -> Result<Self, HashError> {
+        let digest_value = match hash_type {
+            HashType::CRC | HashType::CrcIeee | HashType::CrcKoopman => <[u8; 4]>::try_from(digest)
+                .ok()
+                .map(|checksum| u32::from_be_bytes(checksum).to_string().into_bytes()),
+            HashType::Argon2 => from_utf8(digest)
+                .ok()
+                .filter(|phc| phc.starts_with("$argon2"))
+                .map(|phc| phc.as_bytes().to_vec()),
+            _ => (hash_type.digest_len() == Some(digest.len())).then(|| digest.to_vec()),
+        };
+        match digest_value {
+            Some(digest_value) => Ok(DispnetHash::from_digest(hash_type, digest_value)),
+            None => Err(HashError::InvalidDigestForType {
+                hash_type,
+                length: digest.len(),
+            }),
+        }
+    }
+
+    /// Derive a git-style fan-out storage path from the digest.
+    /// Every leading digest byte up to `fanout` becomes a directory named by its hex value,
+    /// the file name is the full dispnet hash so the hash type stays part of the path.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn storage_path() {
+    ///     let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+    ///     let path = dispnet_hash.storage_path(2);
+    ///     assert_eq!(path, std::path::Path::new("32/32/02001032323538363632303830"));
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn storage_path(&self, fanout: usize) -> PathBuf {
+        let mut path: PathBuf = self
+            .digest_value
+            .iter()
+            .take(fanout)
+            .map(|b| DispnetHash::bytes_to_hex(&[*b]))
+            .collect();
+        path.push(&self.value);
+        path
     }
 
     /// Convert a hexadecimal string to a vector of bytes.
@@ -171,17 +1122,19 @@ impl DispnetHash {
     /// }
     /// ```
     pub fn hex_to_bytes(s: &str) -> Option<Vec<u8>> {
-        if s.len() % 2 == 0 {
-            (0..s.len())
-                .step_by(2)
-                .map(|i| {
-                    s.get(i..i + 2)
-                        .and_then(|sub| u8::from_str_radix(sub, 16).ok())
-                })
-                .collect()
-        } else {
-            None
+        if !s.len().is_multiple_of(2) {
+            return None;
+        }
+        let mut bytes = Vec::with_capacity(s.len() / 2);
+        for pair in s.as_bytes().chunks_exact(2) {
+            let high = HEX_VALUES[pair[0] as usize];
+            let low = HEX_VALUES[pair[1] as usize];
+            if high == INVALID_HEX || low == INVALID_HEX {
+                return None;
+            }
+            bytes.push((high << 4) | low);
         }
+        Some(bytes)
     }
     
     /// Convert a slice of bytes to a hexadecimal string.
@@ -196,7 +1149,9 @@ impl DispnetHash {
     /// }
     /// ```
     pub fn bytes_to_hex(bytes: &[u8]) -> String {
-        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+        let mut hex = String::with_capacity(2 * bytes.len());
+        push_hex(bytes, &mut hex);
+        hex
     }
 
     /// Convert a slice of bytes to a u64 integer.
@@ -222,24 +1177,50 @@ impl DispnetHash {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl fmt::Display for DispnetHash {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.value)
+        self.write_to(f)
+    }
+}
+
+/// Logs the display format (e.g. over RTT), needs `alloc`. Without an allocator log a [`DispnetHashFixed`].
+#[cfg(all(feature = "alloc", feature = "defmt"))]
+impl defmt::Format for DispnetHash {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.value.as_str())
     }
 }
 
+#[cfg(feature = "alloc")]
 impl PartialEq for DispnetHash {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+        self.eq_canonical(other)
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Eq for DispnetHash {}
+
+/// Consistent with `PartialEq`, only the hash type and the digest are hashed.
+#[cfg(feature = "alloc")]
+impl core::hash::Hash for DispnetHash {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.hash_type.hash(state);
+        self.digest_value.hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl PartialEq<String> for DispnetHash {
     fn eq(&self, other: &String) -> bool {
-        self.value == *other
+        other
+            .parse::<DispnetHash>()
+            .is_ok_and(|other_hash| self.eq_canonical(&other_hash))
     }
 }
 
+#[cfg(feature = "alloc")]
 impl FromStr for DispnetHash {
     type Err = HashError;
 
@@ -248,139 +1229,19 @@ impl FromStr for DispnetHash {
     }
 }
 
-#[derive(Debug)]
-struct InternalDispnetHash {
-    pub hash_type: HashType,
-    pub digest_length: usize,
-    pub digest_value: Vec<u8>,
-}
-
-impl InternalDispnetHash {
-    fn new(hash_type: HashType, value: &[u8], config: Option<HashConfig>) -> Self {
-        let mut _hash_config: HashConfig = HashConfig { salt: None };
-        let mut config_hash_salt: Box<Vec<u8>> =
-            Box::new("A8nUz1Pkc0IZ0uJSZNnMlvdLz0T3al5Hjhg2".as_bytes().to_owned());
-        let salt: &[u8];
-
-        if let Some(_hash_config) = config {
-            if let Some(config_hash_salt_value) = _hash_config.salt {
-                config_hash_salt = config_hash_salt_value;
-                salt = &(*config_hash_salt);
-            } else {
-                salt = &(*config_hash_salt);
-            }
-        } else {
-            salt = &(*config_hash_salt);
-        }
-        match hash_type {
-            HashType::Argon2 => {
-                let argon2_config = argon2::Config::default();
-                let hash = argon2::hash_encoded(value, salt, &argon2_config).unwrap();
-                Self {
-                    hash_type: HashType::Argon2,
-                    digest_length: hash.len(),
-                    digest_value: hash.into_bytes().to_vec(),
-                }
-            }
-            HashType::CRC => {
-                let crc32 = crc::Crc::<u32>::new(&crc::CRC_32_ISCSI);
-                let hash = crc32.checksum(value).to_string();
-                Self {
-                    hash_type: HashType::CRC,
-                    digest_length: hash.len(),
-                    digest_value: hash.into_bytes().to_vec(),
-                }
-            }
-            _ => {
-                let hash = blake3::hash(value);
-                let hash_bytes = hash.as_bytes();
-                Self {
-                    hash_type: HashType::Blake3,
-                    digest_length: hash_bytes.len(),
-                    digest_value: hash_bytes.to_vec(),
-                }
-            }
-        }
-    }
-
-    fn parse(hash_value: &str) -> Result<Self, HashError> {
-        let (raw_type, raw_digest_len_value) = hash_value.split_at(2);
-        let (raw_digest_len, raw_digest_value) = raw_digest_len_value.split_at(4);
-        let mut type_result = HashType::Blake3;
-        let raw_type_result = raw_type.parse::<u8>();
-        if let Ok(raw_type) = raw_type_result {
-            match raw_type {
-                3 => {
-                    type_result = HashType::Argon2;
-                }
-                2 => {
-                    type_result = HashType::CRC;
-                }
-                _ => {
-                    type_result = HashType::Blake3;
-                }
-            }
-        } else {
-            println!(
-                "Invalid hash type raw value:{}. Use Blake3 as fallback!",
-                raw_type
-            );
-        }
-
-        let hex_result = DispnetHash::hex_to_bytes(raw_digest_value);
-        if let Some(hash_bytes) = hex_result {
-            let digest_len_result = raw_digest_len.parse::<usize>();
-            if let Ok(hash_bytes_len) = digest_len_result {
-                if hash_bytes_len == hash_bytes.len() {
-                    Ok(Self {
-                        hash_type: type_result,
-                        digest_length: hash_bytes_len,
-                        digest_value: hash_bytes,
-                    })
-                } else {
-                    println!(
-                        "Length missmatch for digest. Length:{} Digest:{}",
-                        hash_bytes_len,
-                        hash_bytes.len()
-                    );
-                    Err(HashError::DigestLengthMissmatch {
-                        length: hash_bytes_len,
-                        digest: hash_bytes,
-                    })
-                }
-            } else {
-                println!("Digest length is not a valid usize:{}", raw_digest_len);
-                Err(HashError::DigestLength {
-                    raw_digest_length: raw_digest_len.to_owned(),
-                })
-            }
-        } else {
-            println!("Invalid digest hex value:{}", raw_digest_value);
-            Err(HashError::InvalidDigest {
-                hex_digest: raw_digest_value.to_owned(),
-            })
-        }
-    }
-}
-
-impl fmt::Display for InternalDispnetHash {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}{:04}{}",
-            self.hash_type,
-            self.digest_length,
-            self.digest_value
-                .iter()
-                .map(|x| format!("{:02x}", x))
-                .collect::<String>()
-        )
-    }
+/// Deterministic pseudo random sequence (splitmix64) for internal hash parameters.
+#[cfg(feature = "std")]
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{DispnetHash, HashType, HashConfig};
+    use crate::{CrcVariant, DispnetHash, HashType, HashConfig, HashError, Purpose, SecurityClass};
 
     #[test]
     fn new_hash() {
@@ -415,12 +1276,22 @@ mod tests {
 
     #[test]
     fn create_argon2_salt_hash() {
-        let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
+        let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
         let display_hash = format!("{}", dispnet_hash);
         assert_eq!(display_hash, "030084246172676f6e326924763d3139246d3d343039362c743d332c703d31244d54497a4e4455324e7a6724686f56354d494638596a39746b39356c467365546279554a6e393336484944586754685533637065643151");
         assert_eq!(dispnet_hash.digest_encoded, 5850567777771008853);
     }
 
+    #[test]
+    fn create_argon2_hash_clamps_lanes() {
+        for (lanes, expected_lanes) in [(0, 1), (1024, 512)] {
+            let config = HashConfig::default().with_argon2_lanes(lanes);
+            let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(config));
+            assert_eq!(dispnet_hash.argon2_params().unwrap().lanes, expected_lanes);
+            assert!(DispnetHash::verify_instance(&dispnet_hash, "test".as_bytes()));
+        }
+    }
+
     #[test]
     fn parse_hash() {
         let dispnet_hash = "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215".parse::<DispnetHash>().unwrap();
@@ -476,8 +1347,8 @@ mod tests {
 
     #[test]
     fn compare_argon2_salt_hash_instances() {
-        let dispnet_hash_1 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
-        let dispnet_hash_2 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
+        let dispnet_hash_1 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
+        let dispnet_hash_2 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
         assert_eq!(dispnet_hash_1, dispnet_hash_2);
     }
 
@@ -504,7 +1375,7 @@ mod tests {
 
     #[test]
     fn compare_argon2_salt_hash_instance_and_prase() {
-        let dispnet_hash_1 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig { salt: Some(Box::new(b"12345678".to_vec())) }));
+        let dispnet_hash_1 = DispnetHash::create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
         let dispnet_hash_2 = "030084246172676f6e326924763d3139246d3d343039362c743d332c703d31244d54497a4e4455324e7a6724686f56354d494638596a39746b39356c467365546279554a6e393336484944586754685533637065643151".parse::<DispnetHash>().unwrap();
         assert_eq!(dispnet_hash_1, dispnet_hash_2);
     }
@@ -527,16 +1398,207 @@ mod tests {
         assert!(!DispnetHash::verify("030084246172676f6e326924763d3139246d3d343039362c743d332c703d31244d54497a4e4455324e7a6724686f56354d494638596a39746b39356c467365546279554a6e393336484944586754685533637065644262", "test".as_bytes()));
     }
 
+    #[test]
+    fn verify_content_hash() {
+        assert!(DispnetHash::verify("02001032323538363632303830", "test".as_bytes()));
+        assert!(!DispnetHash::verify("02001032323538363632303830", "other".as_bytes()));
+        assert!(DispnetHash::verify_instance(&DispnetHash::new("test".as_bytes()), "test".as_bytes()));
+    }
+
+    #[test]
+    fn parse_normalizes_case() {
+        let lower = "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215";
+        let upper = lower.to_uppercase().parse::<DispnetHash>().unwrap();
+        assert_eq!(upper.to_string(), lower);
+        assert!(upper.eq_canonical(&lower.parse::<DispnetHash>().unwrap()));
+        assert!(DispnetHash::new("test".as_bytes()) == lower.to_uppercase());
+        assert!(DispnetHash::hex_to_bytes("+f").is_none());
+        assert!(matches!("0100".parse::<DispnetHash>(), Err(HashError::DigestLength { .. })));
+        assert!(matches!("0ä0032".parse::<DispnetHash>(), Err(HashError::DigestLength { .. })));
+        assert!(DispnetHash::hex_to_bytes("0g").is_none());
+        assert_eq!(DispnetHash::hex_to_bytes("00fFa0").unwrap(), vec![0x00, 0xff, 0xa0]);
+        assert_eq!(DispnetHash::bytes_to_hex(&[0x00, 0xff, 0xa0]), "00ffa0");
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        let expected_hex: String = all_bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(DispnetHash::bytes_to_hex(&all_bytes), expected_hex);
+    }
+
+    #[test]
+    fn normalize() {
+        let lower = "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215";
+        assert_eq!(DispnetHash::normalize(&lower.to_uppercase()).unwrap(), lower);
+        assert_eq!(DispnetHash::normalize(&format!("\t{}\n", lower)).unwrap(), lower);
+        assert!(matches!(DispnetHash::normalize("01003248"), Err(HashError::DigestLengthMissmatch { .. })));
+    }
+
+    #[test]
+    fn digest_len() {
+        for hash_type in [HashType::Blake3, HashType::SimHash, HashType::GitSha1] {
+            let dispnet_hash = DispnetHash::create(hash_type, "test".as_bytes(), None);
+            assert_eq!(hash_type.digest_len(), Some(dispnet_hash.digest_length));
+        }
+        assert_eq!(HashType::CRC.digest_len(), Some(4));
+        assert_eq!(HashType::Argon2.digest_len(), None);
+    }
+
+    #[test]
+    fn crc_variants() {
+        for (crc_variant, checksum) in [(CrcVariant::Castagnoli, 0xe3069283u32), (CrcVariant::Ieee, 0xcbf43926), (CrcVariant::Koopman, 0x2d3dd0ae)] {
+            let config = HashConfig::default().with_crc_variant(crc_variant);
+            let dispnet_hash = DispnetHash::create(HashType::CRC, "123456789".as_bytes(), Some(config));
+            assert_eq!(dispnet_hash.hash_type, crc_variant.hash_type());
+            assert_eq!(dispnet_hash.hash_type.crc_variant(), Some(crc_variant));
+            assert_eq!(dispnet_hash.digest_value, checksum.to_string().into_bytes());
+            let parsed_hash = dispnet_hash.to_string().parse::<DispnetHash>().unwrap();
+            assert!(DispnetHash::verify_instance(&parsed_hash, "123456789".as_bytes()));
+        }
+        let ieee_hash = DispnetHash::create(HashType::CrcIeee, "test".as_bytes(), None);
+        assert!(!DispnetHash::verify(&ieee_hash.to_string().replacen("06", "02", 1), "test".as_bytes()));
+        assert_eq!(HashType::Blake3.crc_variant(), None);
+    }
+
+    #[test]
+    fn parse_digest_for_type() {
+        assert!(matches!("01000474657374".parse::<DispnetHash>(), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 4 })));
+        assert!(matches!("020002ffff".parse::<DispnetHash>(), Err(HashError::InvalidDigestForType { hash_type: HashType::CRC, .. })));
+        assert!(matches!("0200023031".parse::<DispnetHash>(), Err(HashError::InvalidDigestForType { .. })));
+        assert!(matches!("02001034323934393637323936".parse::<DispnetHash>(), Err(HashError::InvalidDigestForType { .. })));
+        assert!("02000130".parse::<DispnetHash>().is_ok());
+        assert!(matches!("0300047465737a".parse::<DispnetHash>(), Err(HashError::InvalidDigestForType { hash_type: HashType::Argon2, .. })));
+        assert!(matches!(DispnetHash::from_bytes(&[4, 0, 1, 0]), Err(HashError::InvalidDigestForType { hash_type: HashType::SimHash, length: 1 })));
+    }
+
+    #[test]
+    fn verify_allowed() {
+        let blake3_hash = DispnetHash::new("test".as_bytes()).to_string();
+        let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None).to_string();
+        assert!(DispnetHash::verify_allowed(&blake3_hash, "test".as_bytes(), &[HashType::Blake3]).unwrap());
+        assert!(!DispnetHash::verify_allowed(&blake3_hash, "other".as_bytes(), &[HashType::Blake3]).unwrap());
+        assert!(matches!(
+            DispnetHash::verify_allowed(&crc_hash, "test".as_bytes(), &[HashType::Blake3]),
+            Err(HashError::DisallowedHashType { hash_type: HashType::CRC })
+        ));
+        assert!(DispnetHash::verify_allowed("01", "test".as_bytes(), &[HashType::Blake3]).is_err());
+    }
+
+    #[test]
+    fn for_purpose() {
+        assert_eq!(HashType::for_purpose(Purpose::ContentIntegrity).security_class(), SecurityClass::Cryptographic);
+        assert_eq!(HashType::for_purpose(Purpose::PasswordStorage).security_class(), SecurityClass::PasswordHash);
+        assert_eq!(HashType::for_purpose(Purpose::FastChecksum), HashType::CRC);
+    }
+
+    #[test]
+    fn security_class() {
+        assert_eq!(HashType::Blake3.security_class(), SecurityClass::Cryptographic);
+        assert_eq!(HashType::CRC.security_class(), SecurityClass::Checksum);
+        assert_eq!(HashType::Argon2.security_class(), SecurityClass::PasswordHash);
+        assert_eq!(HashType::SimHash.security_class(), SecurityClass::Fingerprint);
+    }
+
     #[test]
     fn hex() {
         assert_eq!(DispnetHash::bytes_to_hex("test".as_bytes()), "74657374");
         assert_eq!(DispnetHash::hex_to_bytes("74657374").unwrap(), "test".as_bytes());
     }
 
+    #[test]
+    fn raw_digest() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        assert_eq!(dispnet_hash.raw_hex(), "4878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+        assert_eq!(dispnet_hash.raw_base64(), "SHjKBCXHOfpCf37aIP6EX2suRrpf4qFN9bHjL1BgMhU=");
+    }
+
+    #[test]
+    fn write_to() {
+        let mut line = String::from("hash=");
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::Argon2, HashType::SimHash, HashType::GitSha1] {
+            let dispnet_hash = DispnetHash::create(hash_type, "test".as_bytes(), None);
+            line.truncate(5);
+            dispnet_hash.write_to(&mut line).unwrap();
+            assert_eq!(line, format!("hash={}", dispnet_hash.value));
+        }
+    }
+
+    #[test]
+    fn nil() {
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::SimHash, HashType::GitSha1, HashType::CrcIeee] {
+            let nil_hash = DispnetHash::nil(hash_type).unwrap();
+            assert!(nil_hash.is_nil());
+            assert_eq!(nil_hash.to_string().parse::<DispnetHash>().unwrap(), nil_hash);
+            assert!(!DispnetHash::create(hash_type, "test".as_bytes(), None).is_nil());
+        }
+        assert_eq!(DispnetHash::nil(HashType::CRC).unwrap().to_string(), "02000130");
+        assert!(DispnetHash::nil(HashType::Argon2).is_none());
+    }
+
+    #[test]
+    fn crc_value() {
+        assert_eq!(DispnetHash::create(HashType::CRC, "test".as_bytes(), None).crc_value(), Some(2258662080));
+        assert_eq!(DispnetHash::create(HashType::CrcKoopman, "test".as_bytes(), None).crc_value(), Some(1547283230));
+        assert_eq!(DispnetHash::from_raw_digest(HashType::CrcIeee, &u32::MAX.to_be_bytes()).unwrap().crc_value(), Some(u32::MAX));
+        assert_eq!(DispnetHash::new("test".as_bytes()).crc_value(), None);
+    }
+
+    #[test]
+    fn from_raw_digest() {
+        let blake3_digest = DispnetHash::hex_to_bytes("4878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215").unwrap();
+        assert_eq!(DispnetHash::from_raw_digest(HashType::Blake3, &blake3_digest).unwrap(), DispnetHash::new("test".as_bytes()));
+        assert_eq!(DispnetHash::from_raw_digest(HashType::CRC, &2258662080u32.to_be_bytes()).unwrap(), DispnetHash::create(HashType::CRC, "test".as_bytes(), None));
+        let argon2_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
+        assert_eq!(DispnetHash::from_raw_digest(HashType::Argon2, &argon2_hash.digest_value).unwrap(), argon2_hash);
+        assert!(matches!(DispnetHash::from_raw_digest(HashType::Blake3, &blake3_digest[..8]), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 8 })));
+        assert!(matches!(DispnetHash::from_raw_digest(HashType::Argon2, b"test"), Err(HashError::InvalidDigestForType { .. })));
+    }
+
+    #[test]
+    fn storage_path() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        assert_eq!(dispnet_hash.storage_path(0), std::path::Path::new("0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215"));
+        assert_eq!(dispnet_hash.storage_path(3), std::path::Path::new("48/78/ca/0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215"));
+        assert_eq!(dispnet_hash.storage_path(64).components().count(), 33);
+    }
+
+    #[test]
+    fn hash_bytes() {
+        let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        let bytes = dispnet_hash.to_bytes();
+        assert_eq!(bytes, [&[2, 0, 10][..], b"2258662080"].concat());
+        assert_eq!(DispnetHash::from_bytes(&bytes).unwrap(), dispnet_hash);
+        assert!(matches!(DispnetHash::from_bytes(&[9, 0, 0]), Err(HashError::UnknownHashType { type_code: 9 })));
+        assert!(matches!(DispnetHash::from_bytes(&bytes[..5]), Err(HashError::DigestLengthMissmatch { length: 10, .. })));
+        let long_digest = [&[3, 0x27, 0x10][..], &[b'$'; 10000]].concat();
+        assert!(matches!(DispnetHash::from_bytes(&long_digest), Err(HashError::DigestLength { .. })));
+    }
+
     #[test]
     fn encoded_u64() {
         assert_eq!(DispnetHash::encoded_u64("test".as_bytes()), 1953719668);
         assert_eq!(DispnetHash::encoded_u64("a".as_bytes()), 97);
         assert_eq!(DispnetHash::encoded_u64("aasdsakdljaslfhaksjhuahwiuewasdfgs4354sg".as_bytes()), 7454359211325289319);
     }
+
+    proptest::proptest! {
+        #[test]
+        fn parse_display_round_trip(raw_hash in "[0-9+-]{6}[0-9a-fA-F]{0,80}|\\PC{0,90}") {
+            proptest::prop_assert_eq!(DispnetHash::validate(&raw_hash).is_ok(), raw_hash.parse::<DispnetHash>().is_ok());
+            if let Ok(dispnet_hash) = raw_hash.parse::<DispnetHash>() {
+                let display_hash = dispnet_hash.to_string();
+                let reparsed_hash = display_hash.parse::<DispnetHash>().unwrap();
+                proptest::prop_assert_eq!(&reparsed_hash, &dispnet_hash);
+                proptest::prop_assert_eq!(reparsed_hash.to_string(), display_hash);
+            }
+        }
+
+        #[test]
+        fn create_display_parse_identity(value in proptest::collection::vec(proptest::num::u8::ANY, 0..512), type_index in 0usize..6) {
+            let hash_type = [HashType::Blake3, HashType::CRC, HashType::SimHash, HashType::GitSha1, HashType::CrcIeee, HashType::CrcKoopman][type_index];
+            let dispnet_hash = DispnetHash::create(hash_type, &value, None);
+            let parsed_hash = dispnet_hash.to_string().parse::<DispnetHash>().unwrap();
+            proptest::prop_assert_eq!(parsed_hash.hash_type, dispnet_hash.hash_type);
+            proptest::prop_assert_eq!(&parsed_hash.digest_value, &dispnet_hash.digest_value);
+            proptest::prop_assert_eq!(parsed_hash.digest_encoded, dispnet_hash.digest_encoded);
+            proptest::prop_assert_eq!(DispnetHash::from_bytes(&dispnet_hash.to_bytes()).unwrap(), dispnet_hash);
+        }
+    }
 }
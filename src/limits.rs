@@ -0,0 +1,170 @@
+use alloc::{borrow::ToOwned, vec::Vec};
+
+use crate::{DispnetHash, HashError, HashType, ParseWarning};
+
+/// Upper bounds for parsing untrusted hash strings (see [`DispnetHash::parse_with_limits`]).
+///
+/// The limits are checked before the digest is decoded, so an oversized input costs no more than reading its header.
+/// The default allows inputs up to 4096 characters and digests up to 1024 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximal length of the input in bytes.
+    pub max_input_length: usize,
+    /// Maximal digest length in bytes (as declared in the hash header).
+    pub max_digest_length: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_input_length: 4096,
+            max_digest_length: 1024,
+        }
+    }
+}
+
+/// Per call site parse rules (see [`DispnetHash::parse_with`]).
+///
+/// The default is not strict, allows inputs up to 4096 characters and all hash types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Reject what the default parser tolerates: unknown hash types (parsed as Blake3) and upper case hex.
+    pub strict: bool,
+    /// Maximal length of the input in bytes.
+    pub max_len: usize,
+    /// Only these hash types are accepted, all types if `None`.
+    pub allowed_types: Option<Vec<HashType>>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_len: ParseLimits::default().max_input_length,
+            allowed_types: None,
+        }
+    }
+}
+
+impl DispnetHash {
+    /// Parse a hash string with the options of the call site.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashError, HashType, ParseOptions};
+    ///
+    /// fn parse_content_hash(raw_hash: &str) -> Result<DispnetHash, HashError> {
+    ///     let options = ParseOptions { strict: true, max_len: 128, allowed_types: Some(vec![HashType::Blake3]) };
+    ///     DispnetHash::parse_with(raw_hash, &options)
+    /// }
+    /// ```
+    pub fn parse_with(hash_value: &str, options: &ParseOptions) -> Result<Self, HashError> {
+        if hash_value.len() > options.max_len {
+            return Err(HashError::InputTooLong {
+                length: hash_value.len(),
+                max_length: options.max_len,
+            });
+        }
+        let mut warnings = Vec::new();
+        let hash = DispnetHash::parse_reporting(hash_value, &mut warnings)?;
+        if options.strict {
+            match warnings.into_iter().next() {
+                Some(ParseWarning::UnknownHashType { raw_type }) => {
+                    return Err(HashError::UnknownHashType {
+                        type_code: raw_type.parse().unwrap_or(0),
+                    })
+                }
+                Some(_) => {
+                    return Err(HashError::InvalidDigest {
+                        hex_digest: hash_value[6..].to_owned(),
+                    })
+                }
+                None => {}
+            }
+        }
+        hash.check_allowed_type(options.allowed_types.as_deref())?;
+        Ok(hash)
+    }
+
+    /// Error if the hash type is not one of the allowed types, `None` allows all types.
+    pub(crate) fn check_allowed_type(&self, allowed_types: Option<&[HashType]>) -> Result<(), HashError> {
+        match allowed_types {
+            Some(allowed_types) if !allowed_types.contains(&self.hash_type) => Err(HashError::DisallowedHashType {
+                hash_type: self.hash_type,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse a hash string if it stays within the limits.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashError, ParseLimits};
+    ///
+    /// fn parse_request_hash(raw_hash: &str) -> Result<DispnetHash, HashError> {
+    ///     let limits = ParseLimits { max_input_length: 256, max_digest_length: 64 };
+    ///     DispnetHash::parse_with_limits(raw_hash, &limits)
+    /// }
+    /// ```
+    pub fn parse_with_limits(hash_value: &str, limits: &ParseLimits) -> Result<Self, HashError> {
+        if hash_value.len() > limits.max_input_length {
+            return Err(HashError::InputTooLong {
+                length: hash_value.len(),
+                max_length: limits.max_input_length,
+            });
+        }
+        if let Some(digest_length) = hash_value.get(2..6).and_then(|raw_length| raw_length.parse::<usize>().ok()) {
+            if digest_length > limits.max_digest_length {
+                return Err(HashError::DigestTooLong {
+                    length: digest_length,
+                    max_length: limits.max_digest_length,
+                });
+            }
+        }
+        DispnetHash::parse(hash_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType, ParseLimits, ParseOptions};
+
+    #[test]
+    fn parse_within_limits() {
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let limits = ParseLimits { max_input_length: 70, max_digest_length: 32 };
+        assert_eq!(DispnetHash::parse_with_limits(&dispnet_hash.to_string(), &limits).unwrap(), dispnet_hash);
+        let argon2_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
+        assert!(DispnetHash::parse_with_limits(&argon2_hash.to_string(), &ParseLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn parse_exceeding_limits() {
+        let limits = ParseLimits { max_input_length: 70, max_digest_length: 16 };
+        let long_input = format!("010032{}", "0".repeat(1000));
+        assert!(matches!(DispnetHash::parse_with_limits(&long_input, &limits), Err(HashError::InputTooLong { length: 1006, max_length: 70 })));
+        let blake3_hash = DispnetHash::new("test".as_bytes()).to_string();
+        assert!(matches!(DispnetHash::parse_with_limits(&blake3_hash, &limits), Err(HashError::DigestTooLong { length: 32, max_length: 16 })));
+    }
+
+    #[test]
+    fn parse_with_options() {
+        let blake3_hash = DispnetHash::new("test".as_bytes()).to_string();
+        let unknown_type = format!("09{}", &blake3_hash[2..]);
+        let uppercase_hex = blake3_hash.to_uppercase();
+        let default_options = ParseOptions::default();
+        assert!(DispnetHash::parse_with(&unknown_type, &default_options).is_ok());
+        assert!(DispnetHash::parse_with(&uppercase_hex, &default_options).is_ok());
+
+        let strict_options = ParseOptions { strict: true, ..Default::default() };
+        assert_eq!(DispnetHash::parse_with(&blake3_hash, &strict_options).unwrap().to_string(), blake3_hash);
+        assert!(matches!(DispnetHash::parse_with(&unknown_type, &strict_options), Err(HashError::UnknownHashType { type_code: 9 })));
+        assert!(matches!(DispnetHash::parse_with(&uppercase_hex, &strict_options), Err(HashError::InvalidDigest { .. })));
+
+        let limited_options = ParseOptions { max_len: 32, allowed_types: Some(vec![HashType::CRC]), ..Default::default() };
+        assert!(matches!(DispnetHash::parse_with(&blake3_hash, &limited_options), Err(HashError::InputTooLong { length: 70, max_length: 32 })));
+        assert!(DispnetHash::parse_with("02001032323538363632303830", &limited_options).is_ok());
+        let sha1_hash = DispnetHash::create(HashType::GitSha1, "test".as_bytes(), None).to_string();
+        let allowed_options = ParseOptions { allowed_types: Some(vec![HashType::CRC]), ..Default::default() };
+        assert!(matches!(DispnetHash::parse_with(&sha1_hash, &allowed_options), Err(HashError::DisallowedHashType { hash_type: HashType::GitSha1 })));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{DispnetHash, HashError};
+
+/// Checksum manifest which maps paths to their dispnet hash.
+///
+/// # Display format is structured as followed:
+///
+/// One line per path sorted by path: the dispnet hash, two spaces and the path (like `sha256sum`).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, Manifest};
+///
+/// fn manifest() {
+///     let mut manifest = Manifest::default();
+///     manifest.insert("docs/readme.txt", DispnetHash::new("test".as_bytes()));
+///     assert_eq!(manifest.to_string(), "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215  docs/readme.txt\n");
+/// }
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub entries: BTreeMap<String, DispnetHash>,
+}
+
+/// Paths which differ between two manifests (see [`Manifest::diff`]).
+#[derive(Debug, Default, PartialEq)]
+pub struct ManifestDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl Manifest {
+    /// Add or replace the hash of a path.
+    pub fn insert(&mut self, path: impl Into<String>, hash: DispnetHash) -> Option<DispnetHash> {
+        self.entries.insert(path.into(), hash)
+    }
+
+    /// Compare two manifests and return the added, removed and changed paths (each sorted).
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::Manifest;
+    ///
+    /// fn transfer_set(old_manifest: &str, new_manifest: &str) -> Vec<String> {
+    ///     let old_manifest = old_manifest.parse::<Manifest>().unwrap();
+    ///     let new_manifest = new_manifest.parse::<Manifest>().unwrap();
+    ///     let diff = Manifest::diff(&old_manifest, &new_manifest);
+    ///     diff.added.into_iter().chain(diff.changed).collect()
+    /// }
+    /// ```
+    pub fn diff(old: &Manifest, new: &Manifest) -> ManifestDiff {
+        let mut diff = ManifestDiff::default();
+        for (path, new_hash) in &new.entries {
+            match old.entries.get(path) {
+                None => diff.added.push(path.clone()),
+                Some(old_hash) if old_hash != new_hash => diff.changed.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .entries
+            .keys()
+            .filter(|path| !new.entries.contains_key(*path))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    fn parse(manifest_value: &str) -> Result<Self, HashError> {
+        let mut manifest = Manifest::default();
+        for line in manifest_value.lines().filter(|line| !line.trim().is_empty()) {
+            match line.split_once("  ") {
+                Some((raw_hash, path)) if !path.is_empty() => {
+                    manifest.insert(path, raw_hash.parse::<DispnetHash>()?);
+                }
+                _ => {
+                    return Err(HashError::InvalidManifest {
+                        line: line.to_owned(),
+                    })
+                }
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+impl ManifestDiff {
+    /// True if both manifests are equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (path, hash) in &self.entries {
+            writeln!(f, "{}  {}", hash, path)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        Manifest::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType, Manifest};
+
+    fn manifest(entries: &[(&str, &str)]) -> Manifest {
+        let mut manifest = Manifest::default();
+        for (path, content) in entries {
+            manifest.insert(*path, DispnetHash::create(HashType::CRC, content.as_bytes(), None));
+        }
+        manifest
+    }
+
+    #[test]
+    fn manifest_diff() {
+        let old_manifest = manifest(&[("a.txt", "a"), ("b.txt", "b"), ("c.txt", "c")]);
+        let new_manifest = manifest(&[("a.txt", "a"), ("c.txt", "changed"), ("d.txt", "d")]);
+        let diff = Manifest::diff(&old_manifest, &new_manifest);
+        assert_eq!(diff.added, vec!["d.txt"]);
+        assert_eq!(diff.removed, vec!["b.txt"]);
+        assert_eq!(diff.changed, vec!["c.txt"]);
+        assert!(Manifest::diff(&old_manifest, &manifest(&[("a.txt", "a"), ("b.txt", "b"), ("c.txt", "c")])).is_empty());
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = manifest(&[("dir/file name.txt", "a"), ("b.txt", "b")]);
+        assert_eq!(manifest.to_string().parse::<Manifest>().unwrap(), manifest);
+        assert!(matches!("no separator".parse::<Manifest>(), Err(HashError::InvalidManifest { .. })));
+    }
+}
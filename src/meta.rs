@@ -0,0 +1,205 @@
+use std::{fmt, str::FromStr};
+
+use crate::{DispnetHash, HashConfig, HashError, HashType};
+
+/// The media type length has 4 decimal digits in the display format.
+const MAX_MEDIA_TYPE_LENGTH: usize = 9999;
+
+/// Dispnet hash with the content length and an optional media type of the hashed object.
+///
+/// # Display format is structured as followed:
+///
+/// * First 2 characters are the count of digits from the content length as integer with a leading 0.
+/// * Then comes the content length as integer.
+/// * Then come 4 characters as integer with leading 0 which is the length of the media type (0 if there is none).
+/// * Then comes the media type.
+/// * Then comes the dispnet hash in its display format.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHashMeta, HashType};
+///
+/// fn hash_meta() {
+///     let hash_meta = DispnetHashMeta::create(HashType::Blake3, "test".as_bytes(), Some("text/plain".to_owned()), None).unwrap();
+///     assert_eq!(hash_meta.to_string(), "0140010text/plain0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct DispnetHashMeta {
+    pub hash: DispnetHash,
+    pub content_length: u64,
+    pub media_type: Option<String>,
+}
+
+impl DispnetHashMeta {
+    /// Create the metadata for an already hashed object.
+    /// Fails if the media type is longer than 9999 bytes.
+    pub fn new(hash: DispnetHash, content_length: u64, media_type: Option<String>) -> Result<Self, HashError> {
+        if let Some(media_type) = media_type.as_ref().filter(|media_type| media_type.len() > MAX_MEDIA_TYPE_LENGTH) {
+            return Err(HashError::InvalidMetadata {
+                raw_metadata: media_type.clone(),
+            });
+        }
+        Ok(Self {
+            hash,
+            content_length,
+            media_type,
+        })
+    }
+
+    /// Hash the value and record its length and media type.
+    /// Fails if the media type is longer than 9999 bytes.
+    pub fn create(
+        hash_type: HashType,
+        value: &[u8],
+        media_type: Option<String>,
+        config: Option<HashConfig>,
+    ) -> Result<Self, HashError> {
+        DispnetHashMeta::new(
+            DispnetHash::create(hash_type, value, config),
+            value.len() as u64,
+            media_type,
+        )
+    }
+
+    /// Convert the metadata to its compact binary format.
+    ///
+    /// The binary format is structured as followed:
+    ///
+    /// * First 8 bytes (big-endian) are the content length.
+    /// * Then come 2 bytes (big-endian) which are the length of the media type (0 if there is none).
+    /// * Then comes the media type as UTF-8.
+    /// * Then comes the dispnet hash in its binary format (see [`DispnetHash::to_bytes`]).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let media_type = self.media_type.as_deref().unwrap_or_default();
+        let hash_bytes = self.hash.to_bytes();
+        let mut bytes = Vec::with_capacity(10 + media_type.len() + hash_bytes.len());
+        bytes.extend_from_slice(&self.content_length.to_be_bytes());
+        bytes.extend_from_slice(&(media_type.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(media_type.as_bytes());
+        bytes.extend_from_slice(&hash_bytes);
+        bytes
+    }
+
+    /// Create the metadata from its compact binary format (see [`DispnetHashMeta::to_bytes`]).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HashError> {
+        let invalid_metadata = || HashError::InvalidMetadata {
+            raw_metadata: DispnetHash::bytes_to_hex(bytes),
+        };
+        if bytes.len() < 10 {
+            return Err(invalid_metadata());
+        }
+        let content_length = u64::from_be_bytes(bytes[..8].try_into().unwrap());
+        let media_type_length = u16::from_be_bytes([bytes[8], bytes[9]]) as usize;
+        let media_type = bytes
+            .get(10..(10 + media_type_length))
+            .and_then(|raw_media_type| String::from_utf8(raw_media_type.to_vec()).ok())
+            .ok_or_else(invalid_metadata)?;
+        let hash = DispnetHash::from_bytes(&bytes[(10 + media_type_length)..])?;
+        DispnetHashMeta::new(
+            hash,
+            content_length,
+            if media_type.is_empty() { None } else { Some(media_type) },
+        )
+    }
+
+    fn parse(hash_meta_value: &str) -> Result<Self, HashError> {
+        let invalid_metadata = || HashError::InvalidMetadata {
+            raw_metadata: hash_meta_value.to_owned(),
+        };
+        let content_length_digits = hash_meta_value
+            .get(..2)
+            .and_then(|raw| raw.parse::<usize>().ok())
+            .ok_or_else(invalid_metadata)?;
+        let content_length_end = 2 + content_length_digits;
+        let content_length = hash_meta_value
+            .get(2..content_length_end)
+            .and_then(|raw| raw.parse::<u64>().ok())
+            .ok_or_else(invalid_metadata)?;
+        let media_type_start = content_length_end + 4;
+        let media_type_length = hash_meta_value
+            .get(content_length_end..media_type_start)
+            .and_then(|raw| raw.parse::<usize>().ok())
+            .ok_or_else(invalid_metadata)?;
+        let media_type_end = media_type_start + media_type_length;
+        let media_type = hash_meta_value
+            .get(media_type_start..media_type_end)
+            .ok_or_else(invalid_metadata)?;
+        let hash = hash_meta_value[media_type_end..].parse::<DispnetHash>()?;
+        DispnetHashMeta::new(
+            hash,
+            content_length,
+            if media_type.is_empty() { None } else { Some(media_type.to_owned()) },
+        )
+    }
+}
+
+impl fmt::Display for DispnetHashMeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let content_length = self.content_length.to_string();
+        let media_type = self.media_type.as_deref().unwrap_or_default();
+        write!(
+            f,
+            "{:02}{}{:04}{}{}",
+            content_length.len(),
+            content_length,
+            media_type.len(),
+            media_type,
+            self.hash
+        )
+    }
+}
+
+impl FromStr for DispnetHashMeta {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        DispnetHashMeta::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, DispnetHashMeta, HashError, HashType};
+
+    #[test]
+    fn hash_meta() {
+        let hash_meta = DispnetHashMeta::create(HashType::Blake3, "test".as_bytes(), Some("text/plain".to_owned()), None).unwrap();
+        assert_eq!(hash_meta.content_length, 4);
+        assert_eq!(hash_meta.to_string(), "0140010text/plain0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+        let hash_meta_crc = DispnetHashMeta::create(HashType::CRC, "test".as_bytes(), None, None).unwrap();
+        assert_eq!(hash_meta_crc.to_string(), "014000002001032323538363632303830");
+    }
+
+    #[test]
+    fn parse_hash_meta() {
+        let hash_meta = "0140010text/plain0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215".parse::<DispnetHashMeta>().unwrap();
+        assert_eq!(hash_meta.content_length, 4);
+        assert_eq!(hash_meta.media_type, Some("text/plain".to_owned()));
+        assert_eq!(hash_meta.hash, DispnetHash::new("test".as_bytes()));
+        assert!(matches!("99".parse::<DispnetHashMeta>(), Err(HashError::InvalidMetadata { .. })));
+    }
+
+    #[test]
+    fn hash_meta_bytes() {
+        let hash_meta = DispnetHashMeta::create(HashType::Blake3, "test".as_bytes(), Some("text/plain".to_owned()), None).unwrap();
+        let bytes = hash_meta.to_bytes();
+        assert_eq!(bytes.len(), 8 + 2 + 10 + 3 + 32);
+        assert_eq!(DispnetHashMeta::from_bytes(&bytes).unwrap(), hash_meta);
+        assert!(matches!(DispnetHashMeta::from_bytes(&bytes[..12]), Err(HashError::InvalidMetadata { .. })));
+    }
+
+    #[test]
+    fn media_type_too_long() {
+        let media_type = "a".repeat(10000);
+        assert!(matches!(
+            DispnetHashMeta::create(HashType::Blake3, "test".as_bytes(), Some(media_type.clone()), None),
+            Err(HashError::InvalidMetadata { .. })
+        ));
+        let mut bytes = 0u64.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&10000u16.to_be_bytes());
+        bytes.extend_from_slice(media_type.as_bytes());
+        bytes.extend_from_slice(&DispnetHash::new("test".as_bytes()).to_bytes());
+        assert!(matches!(DispnetHashMeta::from_bytes(&bytes), Err(HashError::InvalidMetadata { .. })));
+    }
+}
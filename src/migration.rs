@@ -0,0 +1,99 @@
+use crate::{DispnetHash, HashError, HashPolicy, HashType};
+
+/// Mapping from a deprecated hash to the hash which replaces it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationRecord {
+    pub old_hash: DispnetHash,
+    pub new_hash: DispnetHash,
+}
+
+/// Re-hash objects of an existing store on access until no hash violates the policy anymore.
+///
+/// # Usage
+/// ```
+/// use std::collections::HashMap;
+/// use dispnet_hash::{DispnetHash, HashError, HashPolicy, HashType, LazyMigration};
+///
+/// fn load(store: &HashMap<String, Vec<u8>>, hash: &DispnetHash) -> Result<DispnetHash, HashError> {
+///     let migration = LazyMigration::new(HashType::Blake3, HashPolicy {
+///         allowed_types: Some(vec![HashType::Blake3]),
+///         ..Default::default()
+///     });
+///     let (hash, record) = migration.migrate(hash, |hash| {
+///         store.get(&hash.to_string()).cloned().ok_or(HashError::Undefined)
+///     })?;
+///     if let Some(record) = record {
+///         println!("{} -> {}", record.old_hash, record.new_hash);
+///     }
+///     Ok(hash)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyMigration {
+    target: HashType,
+    policy: HashPolicy,
+}
+
+impl LazyMigration {
+    /// Create a migration which replaces hashes violating the policy with a hash of the target type.
+    pub fn new(target: HashType, policy: HashPolicy) -> Self {
+        Self { target, policy }
+    }
+
+    /// Returns the hash unchanged if it fulfills the policy.
+    /// Otherwise the value is fetched, verified against the old hash and hashed with the target type.
+    pub fn migrate<F>(&self, hash: &DispnetHash, fetch: F) -> Result<(DispnetHash, Option<MigrationRecord>), HashError>
+    where
+        F: FnOnce(&DispnetHash) -> Result<Vec<u8>, HashError>,
+    {
+        if self.policy.check(hash).is_ok() {
+            return Ok((hash.clone(), None));
+        }
+        let value = fetch(hash)?;
+        if !DispnetHash::verify_instance(hash, &value) {
+            return Err(HashError::ContentMismatch { hash: hash.to_string() });
+        }
+        let new_hash = DispnetHash::create(self.target, &value, None);
+        self.policy.check(&new_hash)?;
+        let record = MigrationRecord {
+            old_hash: hash.clone(),
+            new_hash: new_hash.clone(),
+        };
+        Ok((new_hash, Some(record)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashPolicy, HashType, LazyMigration};
+
+    fn blake3_only() -> HashPolicy {
+        HashPolicy { allowed_types: Some(vec![HashType::Blake3]), ..Default::default() }
+    }
+
+    #[test]
+    fn migrate() {
+        let migration = LazyMigration::new(HashType::Blake3, blake3_only());
+        let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        let (new_hash, record) = migration.migrate(&crc_hash, |_| Ok(b"test".to_vec())).unwrap();
+        assert_eq!(new_hash, DispnetHash::new("test".as_bytes()));
+        let record = record.unwrap();
+        assert_eq!(record.old_hash, crc_hash);
+        assert_eq!(record.new_hash, new_hash);
+
+        let (same_hash, record) = migration.migrate(&new_hash, |_| panic!("no fetch for valid hashes")).unwrap();
+        assert_eq!(same_hash, new_hash);
+        assert!(record.is_none());
+    }
+
+    #[test]
+    fn migrate_errors() {
+        let migration = LazyMigration::new(HashType::Blake3, blake3_only());
+        let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        assert!(matches!(migration.migrate(&crc_hash, |_| Ok(b"other".to_vec())), Err(HashError::ContentMismatch { .. })));
+        assert!(matches!(migration.migrate(&crc_hash, |_| Err(HashError::Undefined)), Err(HashError::Undefined)));
+
+        let wrong_target = LazyMigration::new(HashType::CRC, blake3_only());
+        assert!(matches!(wrong_target.migrate(&crc_hash, |_| Ok(b"test".to_vec())), Err(HashError::PolicyViolation { .. })));
+    }
+}
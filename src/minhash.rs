@@ -0,0 +1,115 @@
+use crate::{splitmix64, DispnetHash};
+
+const MERSENNE_PRIME: u64 = (1 << 61) - 1;
+const MINHASH_SEED: u64 = 0x6469_7370_6e65_7431;
+
+/// MinHash signature builder to estimate the Jaccard similarity of token sets.
+///
+/// The permutations are derived from a fixed seed, signatures built with the same
+/// number of permutations are comparable across processes and machines.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::MinHash;
+///
+/// fn minhash() {
+///     let minhash = MinHash::new(128);
+///     let signature_1 = minhash.signature("the quick brown fox".split(' '));
+///     let signature_2 = minhash.signature("the quick brown dog".split(' '));
+///     let similarity = signature_1.jaccard(&signature_2);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MinHash {
+    permutations: Vec<(u64, u64)>,
+}
+
+/// MinHash signature with the minimum value per permutation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinHashSignature {
+    pub values: Vec<u64>,
+}
+
+impl MinHash {
+    /// Create a MinHash builder with `num_perm` permutations.
+    /// More permutations lower the estimation error (about `1 / sqrt(num_perm)`).
+    pub fn new(num_perm: usize) -> Self {
+        let mut state = MINHASH_SEED;
+        let permutations = (0..num_perm)
+            .map(|_| {
+                let a = splitmix64(&mut state) % (MERSENNE_PRIME - 1) + 1;
+                let b = splitmix64(&mut state) % MERSENNE_PRIME;
+                (a, b)
+            })
+            .collect();
+        Self { permutations }
+    }
+
+    /// Build the signature of the tokens. Every token is hashed with Blake3.
+    pub fn signature<I, T>(&self, tokens: I) -> MinHashSignature
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        self.signature_from_hashes(
+            tokens
+                .into_iter()
+                .map(|token| DispnetHash::encoded_u64(blake3::hash(token.as_ref()).as_bytes())),
+        )
+    }
+
+    /// Build the signature from already hashed tokens (e.g. `digest_encoded` of dispnet hashes).
+    pub fn signature_from_hashes<I>(&self, token_hashes: I) -> MinHashSignature
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut values = vec![u64::MAX; self.permutations.len()];
+        for token_hash in token_hashes {
+            let x = (token_hash % MERSENNE_PRIME) as u128;
+            for (value, (a, b)) in values.iter_mut().zip(self.permutations.iter()) {
+                let permuted = ((*a as u128 * x + *b as u128) % MERSENNE_PRIME as u128) as u64;
+                if permuted < *value {
+                    *value = permuted;
+                }
+            }
+        }
+        MinHashSignature { values }
+    }
+}
+
+impl MinHashSignature {
+    /// Estimate the Jaccard similarity (0.0 - 1.0) with another signature.
+    /// Both signatures must be built with the same number of permutations, otherwise
+    /// the missing values count as mismatches.
+    pub fn jaccard(&self, other: &MinHashSignature) -> f64 {
+        let len = self.values.len().max(other.values.len());
+        if len == 0 {
+            return 0.0;
+        }
+        let matches = self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .filter(|(a, b)| a == b)
+            .count();
+        matches as f64 / len as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MinHash;
+
+    #[test]
+    fn minhash_jaccard() {
+        let minhash = MinHash::new(256);
+        let tokens_1: Vec<String> = (0..100).map(|i| format!("token{}", i)).collect();
+        let tokens_2: Vec<String> = (50..150).map(|i| format!("token{}", i)).collect();
+        let signature_1 = minhash.signature(&tokens_1);
+        let signature_2 = minhash.signature(&tokens_2);
+        assert_eq!(signature_1.jaccard(&minhash.signature(&tokens_1)), 1.0);
+        let similarity = signature_1.jaccard(&signature_2);
+        assert!((similarity - 1.0 / 3.0).abs() < 0.1, "similarity {}", similarity);
+        assert!(signature_1.jaccard(&minhash.signature(["other"])) < 0.1);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{DispnetHash, DispnetHasher, HashConfig, HashType};
+
+/// How [`DispnetHash::combine_with`] treats the order of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombineMode {
+    /// The composite changes if the parts are reordered (multipart uploads, chunked files).
+    Ordered,
+    /// The composite only depends on the parts, not their order (set identity).
+    /// Duplicate parts are kept, so it identifies a multiset.
+    Unordered,
+}
+
+impl DispnetHash {
+    /// Combine hashes of parts into one composite hash (ordered).
+    ///
+    /// The composite is the hash of the concatenated binary part hashes (see [`DispnetHash::to_bytes`]),
+    /// like the ETag of a multipart upload. Parts can be hashed independently (e.g. on different workers)
+    /// and verified against a single composite hash.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn combine_parts() {
+    ///     let part_1 = DispnetHash::create(HashType::Blake3, "te".as_bytes(), None);
+    ///     let part_2 = DispnetHash::create(HashType::Blake3, "st".as_bytes(), None);
+    ///     let composite_hash = DispnetHash::combine(HashType::Blake3, &[part_1, part_2]);
+    ///     assert_eq!(composite_hash, DispnetHash::create_multipart(HashType::Blake3, "test".as_bytes(), 2));
+    /// }
+    /// ```
+    pub fn combine(hash_type: HashType, parts: &[DispnetHash]) -> DispnetHash {
+        DispnetHash::combine_with(hash_type, parts, CombineMode::Ordered)
+    }
+
+    /// Combine hashes of parts into one composite hash (see [`DispnetHash::combine`]).
+    /// `CombineMode::Unordered` sorts the binary part hashes before they are hashed.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{CombineMode, DispnetHash, HashType};
+    ///
+    /// fn tag_set_hash(tags: &[&str]) -> DispnetHash {
+    ///     let tag_hashes: Vec<DispnetHash> = tags.iter().map(|tag| DispnetHash::new(tag.as_bytes())).collect();
+    ///     DispnetHash::combine_with(HashType::Blake3, &tag_hashes, CombineMode::Unordered)
+    /// }
+    /// ```
+    pub fn combine_with(hash_type: HashType, parts: &[DispnetHash], mode: CombineMode) -> DispnetHash {
+        let mut part_hashes: Vec<Vec<u8>> = parts.iter().map(DispnetHash::to_bytes).collect();
+        if mode == CombineMode::Unordered {
+            part_hashes.sort_unstable();
+        }
+        DispnetHash::create(hash_type, &part_hashes.concat(), None)
+    }
+
+    /// Hash multiple slices as one value with unambiguous framing, every part is prefixed with
+    /// its length (8 bytes little-endian). `["ab", "c"]` and `["a", "bc"]` have different hashes,
+    /// the parts are hashed without concatenating them.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn record_hash(key: &[u8], value: &[u8]) -> DispnetHash {
+    ///     DispnetHash::create_parts(HashType::Blake3, &[key, value], None)
+    /// }
+    /// ```
+    pub fn create_parts(hash_type: HashType, parts: &[&[u8]], config: Option<HashConfig>) -> DispnetHash {
+        let mut hasher = DispnetHasher::new(hash_type, config);
+        for part in parts {
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+        hasher.finalize()
+    }
+
+    /// Hash the value in parts of `part_size` bytes and combine the part hashes (see [`DispnetHash::combine`]).
+    pub fn create_multipart(hash_type: HashType, value: &[u8], part_size: usize) -> DispnetHash {
+        let parts: Vec<DispnetHash> = value
+            .chunks(part_size.max(1))
+            .map(|part| DispnetHash::create(hash_type, part, None))
+            .collect();
+        DispnetHash::combine(hash_type, &parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CombineMode, DispnetHash, HashType};
+
+    #[test]
+    fn unordered_combine() {
+        let parts: Vec<DispnetHash> = ["a", "b", "c", "a"].iter().map(|part| DispnetHash::new(part.as_bytes())).collect();
+        let mut reversed_parts = parts.clone();
+        reversed_parts.reverse();
+        let unordered_hash = DispnetHash::combine_with(HashType::Blake3, &parts, CombineMode::Unordered);
+        assert_eq!(unordered_hash, DispnetHash::combine_with(HashType::Blake3, &reversed_parts, CombineMode::Unordered));
+        assert_ne!(unordered_hash, DispnetHash::combine_with(HashType::Blake3, &parts[..3], CombineMode::Unordered));
+        assert_ne!(DispnetHash::combine(HashType::Blake3, &parts), DispnetHash::combine(HashType::Blake3, &reversed_parts));
+    }
+
+    #[test]
+    fn framed_parts() {
+        let split_1 = DispnetHash::create_parts(HashType::Blake3, &[b"ab", b"c"], None);
+        let split_2 = DispnetHash::create_parts(HashType::Blake3, &[b"a", b"bc"], None);
+        assert_ne!(split_1, split_2);
+        assert_ne!(DispnetHash::create_parts(HashType::Blake3, &[b"abc", b""], None), DispnetHash::create_parts(HashType::Blake3, &[b"abc"], None));
+        let framed_value = [&2u64.to_le_bytes()[..], b"ab", &1u64.to_le_bytes(), b"c"].concat();
+        assert_eq!(split_1, DispnetHash::create(HashType::Blake3, &framed_value, None));
+        assert_eq!(
+            DispnetHash::create_parts(HashType::CRC, &[b"ab", b"c"], None),
+            DispnetHash::create(HashType::CRC, &framed_value, None)
+        );
+    }
+
+    #[test]
+    fn multipart_hash() {
+        let data: Vec<u8> = (0..10_000).map(|i| (i % 251) as u8).collect();
+        let parts: Vec<DispnetHash> = data.chunks(4096).map(|part| DispnetHash::create(HashType::CRC, part, None)).collect();
+        let composite_hash = DispnetHash::create_multipart(HashType::CRC, &data, 4096);
+        assert_eq!(composite_hash, DispnetHash::combine(HashType::CRC, &parts));
+        assert_ne!(composite_hash, DispnetHash::create(HashType::CRC, &data, None));
+        assert_ne!(composite_hash, DispnetHash::create_multipart(HashType::CRC, &data, 2048));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{DispnetHash, DispnetHasher, HashConfig, HashType};
+
+/// Hasher bound to a namespace, hashes of different namespaces never collide even for the same value.
+///
+/// The namespace is used as [`HashConfig::context`] for every hash.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::NamespacedHasher;
+///
+/// fn block_id(block: &[u8]) -> String {
+///     let blocks = NamespacedHasher::new("dispnet/blocks/v1");
+///     blocks.hash(block).to_string()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespacedHasher {
+    namespace: String,
+    hash_type: HashType,
+}
+
+impl NamespacedHasher {
+    /// Namespaced Blake3 hasher.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self::with_hash_type(namespace, HashType::Blake3)
+    }
+
+    pub fn with_hash_type(namespace: impl Into<String>, hash_type: HashType) -> Self {
+        Self {
+            namespace: namespace.into(),
+            hash_type,
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn hash_type(&self) -> HashType {
+        self.hash_type
+    }
+
+    /// Hash the value in the namespace.
+    pub fn hash(&self, value: &[u8]) -> DispnetHash {
+        DispnetHash::create(self.hash_type, value, Some(self.config()))
+    }
+
+    /// Verify that the hash was created for the value in the namespace.
+    #[must_use]
+    pub fn verify(&self, hash: &DispnetHash, value: &[u8]) -> bool {
+        hash.hash_type == self.hash_type && DispnetHash::verify_in_context(hash, value, &self.namespace)
+    }
+
+    /// Streaming hasher in the namespace.
+    pub fn hasher(&self) -> DispnetHasher {
+        DispnetHasher::new(self.hash_type, Some(self.config()))
+    }
+
+    fn config(&self) -> HashConfig {
+        HashConfig::default().with_context(self.namespace.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashType, NamespacedHasher};
+
+    #[test]
+    fn namespaces_do_not_collide() {
+        let blocks = NamespacedHasher::new("dispnet/blocks/v1");
+        let users = NamespacedHasher::new("dispnet/users/v1");
+        let block_hash = blocks.hash("test".as_bytes());
+        assert_ne!(block_hash, users.hash("test".as_bytes()));
+        assert_ne!(block_hash, DispnetHash::new("test".as_bytes()));
+        assert!(blocks.verify(&block_hash, "test".as_bytes()));
+        assert!(!users.verify(&block_hash, "test".as_bytes()));
+
+        let mut hasher = blocks.hasher();
+        hasher.update("te".as_bytes());
+        hasher.update("st".as_bytes());
+        assert_eq!(hasher.finalize(), block_hash);
+    }
+
+    #[test]
+    fn namespaced_hash_type() {
+        let blocks = NamespacedHasher::with_hash_type("dispnet/blocks/v1", HashType::CRC);
+        let block_hash = blocks.hash("test".as_bytes());
+        assert_eq!(block_hash.hash_type, HashType::CRC);
+        assert!(blocks.verify(&block_hash, "test".as_bytes()));
+        assert!(!NamespacedHasher::new("dispnet/blocks/v1").verify(&block_hash, "test".as_bytes()));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{HashError, HashType};
+
+/// Select the hash type both peers support, preferring the local order.
+/// Returns `None` if there is no common hash type.
+/// # Usage
+/// ```
+/// use dispnet_hash::{negotiate, HashType};
+///
+/// fn negotiate_hash_type() {
+///     let hash_type = negotiate(&[HashType::Blake3, HashType::CRC], &[HashType::CRC, HashType::Blake3]);
+///     assert_eq!(hash_type, Some(HashType::Blake3));
+/// }
+/// ```
+pub fn negotiate(local_prefs: &[HashType], remote_prefs: &[HashType]) -> Option<HashType> {
+    local_prefs
+        .iter()
+        .find(|hash_type| remote_prefs.contains(hash_type))
+        .copied()
+}
+
+/// Encode a preference list for the wire as the 2 character type codes in order (e.g. `0102`).
+pub fn encode_preferences(prefs: &[HashType]) -> String {
+    prefs.iter().map(|hash_type| hash_type.to_string()).collect()
+}
+
+/// Decode a preference list (see [`encode_preferences`]).
+/// Unknown type codes (e.g. from a peer with a newer version) are skipped.
+/// # Usage
+/// ```
+/// use dispnet_hash::{decode_preferences, encode_preferences, HashType};
+///
+/// fn preferences() {
+///     let prefs = decode_preferences("019901").unwrap();
+///     assert_eq!(prefs, vec![HashType::Blake3, HashType::Blake3]);
+///     assert_eq!(encode_preferences(&prefs), "0101");
+/// }
+/// ```
+pub fn decode_preferences(raw_preferences: &str) -> Result<Vec<HashType>, HashError> {
+    if !raw_preferences.len().is_multiple_of(2) || !raw_preferences.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(HashError::InvalidPreferences {
+            raw_preferences: raw_preferences.to_owned(),
+        });
+    }
+    Ok(raw_preferences
+        .as_bytes()
+        .chunks(2)
+        .filter_map(|code| HashType::from_code((code[0] - b'0') * 10 + (code[1] - b'0')))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode_preferences, encode_preferences, negotiate, HashError, HashType};
+
+    #[test]
+    fn negotiate_hash_type() {
+        let local = [HashType::Blake3, HashType::GitSha1, HashType::CRC];
+        assert_eq!(negotiate(&local, &[HashType::CRC, HashType::GitSha1]), Some(HashType::GitSha1));
+        assert_eq!(negotiate(&local, &[HashType::Argon2]), None);
+        assert_eq!(negotiate(&[], &local), None);
+    }
+
+    #[test]
+    fn preferences_wire_encoding() {
+        let prefs = [HashType::Blake3, HashType::CRC, HashType::GitSha1];
+        assert_eq!(encode_preferences(&prefs), "010205");
+        assert_eq!(decode_preferences("010205").unwrap(), prefs);
+        assert_eq!(decode_preferences("").unwrap(), vec![]);
+        assert!(matches!(decode_preferences("01a"), Err(HashError::InvalidPreferences { .. })));
+    }
+}
@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+use utoipa::{
+    openapi::{schema::SchemaType, ObjectBuilder, RefOr, Schema, Type},
+    PartialSchema, ToSchema,
+};
+
+use crate::DispnetHash;
+
+/// Display format: 2 digit type, 4 digit digest length and the digest as hex.
+const HASH_PATTERN: &str = "^[0-9]{6}([0-9a-fA-F]{2})*$";
+
+impl PartialSchema for DispnetHash {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::new(Type::String))
+            .pattern(Some(HASH_PATTERN))
+            .description(Some(
+                "Self describing dispnet hash: 2 digit hash type, 4 digit digest length in bytes and the digest as hex.",
+            ))
+            .examples([
+                "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215",
+                "02001032323538363632303830",
+            ])
+            .into()
+    }
+}
+
+impl ToSchema for DispnetHash {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("DispnetHash")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::{openapi::{RefOr, Schema}, PartialSchema, ToSchema};
+
+    use crate::DispnetHash;
+
+    #[test]
+    fn hash_schema() {
+        assert_eq!(DispnetHash::name(), "DispnetHash");
+        let RefOr::T(Schema::Object(schema)) = DispnetHash::schema() else {
+            panic!("DispnetHash schema must be an inline object schema");
+        };
+        let pattern = schema.pattern.unwrap();
+        assert_eq!(pattern, "^[0-9]{6}([0-9a-fA-F]{2})*$");
+        assert_eq!(schema.examples.len(), 2);
+    }
+}
@@ -0,0 +1,77 @@
+use password_hash::{Error, PasswordHasher, PasswordVerifier, Result};
+
+use crate::{argon2_backend::MIN_SALT_LENGTH, DispnetHash, HashConfig, HashType};
+
+/// Argon2 password hasher which implements the RustCrypto `password-hash` traits,
+/// so dispnet hashes can be used by frameworks which are generic over them.
+///
+/// Hashes are verified from a [`DispnetHash`] or its display format (`str`).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, DispnetPasswordHasher};
+/// use password_hash::{PasswordHasher, PasswordVerifier};
+///
+/// fn login() {
+///     let hash: DispnetHash = DispnetPasswordHasher.hash_password_with_salt(b"secret", b"12345678").unwrap();
+///     let stored_hash = hash.to_string();
+///     assert!(DispnetPasswordHasher.verify_password(b"secret", stored_hash.as_str()).is_ok());
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DispnetPasswordHasher;
+
+impl PasswordHasher<DispnetHash> for DispnetPasswordHasher {
+    fn hash_password_with_salt(&self, password: &[u8], salt: &[u8]) -> Result<DispnetHash> {
+        if salt.len() < MIN_SALT_LENGTH {
+            return Err(Error::SaltInvalid);
+        }
+        let config = HashConfig::default().with_salt(salt);
+        Ok(DispnetHash::create(HashType::Argon2, password, Some(config)))
+    }
+}
+
+impl PasswordVerifier<DispnetHash> for DispnetPasswordHasher {
+    fn verify_password(&self, password: &[u8], hash: &DispnetHash) -> Result<()> {
+        if hash.hash_type != HashType::Argon2 {
+            return Err(Error::Algorithm);
+        }
+        if DispnetHash::verify_instance(hash, password) {
+            Ok(())
+        } else {
+            Err(Error::PasswordInvalid)
+        }
+    }
+}
+
+impl PasswordVerifier<str> for DispnetPasswordHasher {
+    fn verify_password(&self, password: &[u8], hash: &str) -> Result<()> {
+        let hash = hash.parse::<DispnetHash>().map_err(|_| Error::EncodingInvalid)?;
+        self.verify_password(password, &hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use password_hash::{Error, PasswordHasher, PasswordVerifier};
+
+    use crate::{DispnetHash, DispnetPasswordHasher, HashType};
+
+    #[test]
+    fn password_hasher() {
+        let hash: DispnetHash = DispnetPasswordHasher.hash_password_with_salt(b"test", b"12345678").unwrap();
+        assert_eq!(hash.hash_type, HashType::Argon2);
+        assert!(DispnetPasswordHasher.verify_password(b"test", &hash).is_ok());
+        assert!(DispnetPasswordHasher.verify_password(b"test", hash.to_string().as_str()).is_ok());
+        assert_eq!(DispnetPasswordHasher.verify_password(b"wrong", &hash), Err(Error::PasswordInvalid));
+        let short_salt: password_hash::Result<DispnetHash> = DispnetPasswordHasher.hash_password_with_salt(b"test", b"1234");
+        assert_eq!(short_salt.unwrap_err(), Error::SaltInvalid);
+    }
+
+    #[test]
+    fn password_verifier_rejects_other_hashes() {
+        let blake3_hash = DispnetHash::new("test".as_bytes());
+        assert_eq!(DispnetPasswordHasher.verify_password(b"test", &blake3_hash), Err(Error::Algorithm));
+        assert_eq!(DispnetPasswordHasher.verify_password(b"test", "no hash"), Err(Error::EncodingInvalid));
+    }
+}
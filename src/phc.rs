@@ -0,0 +1,152 @@
+use alloc::{borrow::ToOwned, string::String};
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+use core::str::from_utf8;
+
+use crate::{DispnetHash, HashError, HashType};
+
+/// Parameters of an Argon2 hash in the PHC string format
+/// (`$argon2id$v=19$m=4096,t=3,p=1$<salt>$<hash>`).
+/// `variant` is the algorithm identifier (`argon2d`, `argon2i` or `argon2id`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argon2Params {
+    pub variant: String,
+    pub version: u32,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub lanes: u32,
+}
+
+impl Argon2Params {
+    pub(crate) fn parse(phc: &str) -> Option<Self> {
+        let mut fields = phc.strip_prefix('$')?.split('$');
+        let variant = fields.next().filter(|variant| variant.starts_with("argon2"))?;
+        let mut field = fields.next()?;
+        // The version field is optional in the PHC format (missing means 0x10).
+        let version = match field.strip_prefix("v=") {
+            Some(raw_version) => {
+                let version = raw_version.parse::<u32>().ok()?;
+                field = fields.next()?;
+                version
+            }
+            None => 0x10,
+        };
+        let (mut memory_kib, mut iterations, mut lanes) = (None, None, None);
+        for param in field.split(',') {
+            let (name, raw_value) = param.split_once('=')?;
+            let value = raw_value.parse::<u32>().ok()?;
+            match name {
+                "m" => memory_kib = Some(value),
+                "t" => iterations = Some(value),
+                "p" => lanes = Some(value),
+                _ => {}
+            }
+        }
+        Some(Self {
+            variant: variant.to_owned(),
+            version,
+            memory_kib: memory_kib?,
+            iterations: iterations?,
+            lanes: lanes?,
+        })
+    }
+}
+
+impl DispnetHash {
+    /// Parameters of an Argon2 hash, `None` for all other hash types or an invalid PHC string.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn needs_rehash(dispnet_hash: &DispnetHash) -> bool {
+    ///     dispnet_hash
+    ///         .argon2_params()
+    ///         .map_or(false, |params| params.memory_kib < 19456 || params.variant != "argon2id")
+    /// }
+    /// ```
+    pub fn argon2_params(&self) -> Option<Argon2Params> {
+        if self.hash_type != HashType::Argon2 {
+            return None;
+        }
+        from_utf8(&self.digest_value).ok().and_then(Argon2Params::parse)
+    }
+
+    /// Length of the decoded Argon2 output (the last field of the PHC string), `None` for all other hash types.
+    pub(crate) fn argon2_output_len(&self) -> Option<usize> {
+        if self.hash_type != HashType::Argon2 {
+            return None;
+        }
+        let encoded_hash = from_utf8(&self.digest_value).ok()?.rsplit('$').next()?;
+        STANDARD_NO_PAD.decode(encoded_hash).ok().map(|output| output.len())
+    }
+
+    /// Standard PHC string of a password hash (without the dispnet type and length header),
+    /// `None` for all other hash types.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn store_password(password: &str) -> String {
+    ///     let dispnet_hash = DispnetHash::create(HashType::Argon2, password.as_bytes(), None);
+    ///     dispnet_hash.to_phc().unwrap()
+    /// }
+    /// ```
+    pub fn to_phc(&self) -> Option<String> {
+        self.argon2_params()?;
+        from_utf8(&self.digest_value).ok().map(str::to_owned)
+    }
+
+    /// Wrap a PHC string (e.g. from another password store) as dispnet hash.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn verify_password(phc: &str, password: &str) -> bool {
+    ///     DispnetHash::from_phc(phc).map_or(false, |hash| DispnetHash::verify_instance(&hash, password.as_bytes()))
+    /// }
+    /// ```
+    pub fn from_phc(phc: &str) -> Result<Self, HashError> {
+        if Argon2Params::parse(phc).is_none() {
+            return Err(HashError::InvalidDigestForType {
+                hash_type: HashType::Argon2,
+                length: phc.len(),
+            });
+        }
+        Ok(DispnetHash::from_digest(HashType::Argon2, phc.as_bytes().to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Argon2Params, DispnetHash, HashError, HashType};
+
+    #[test]
+    fn argon2_params() {
+        let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
+        let params = dispnet_hash.argon2_params().unwrap();
+        assert_eq!(params.variant, "argon2i");
+        assert_eq!(params.version, 19);
+        assert_eq!((params.memory_kib, params.iterations, params.lanes), (4096, 3, 1));
+        assert_eq!(DispnetHash::new("test".as_bytes()).argon2_params(), None);
+    }
+
+    #[test]
+    fn phc_round_trip() {
+        let dispnet_hash = DispnetHash::create(HashType::Argon2, "test".as_bytes(), None);
+        let phc = dispnet_hash.to_phc().unwrap();
+        assert!(phc.starts_with("$argon2i$v=19$m=4096,t=3,p=1$"));
+        let phc_hash = DispnetHash::from_phc(&phc).unwrap();
+        assert_eq!(phc_hash, dispnet_hash);
+        assert!(DispnetHash::verify_instance(&phc_hash, "test".as_bytes()));
+        assert_eq!(DispnetHash::new("test".as_bytes()).to_phc(), None);
+        assert!(matches!(DispnetHash::from_phc("$scrypt$ln=16,r=8,p=1$c2FsdA$aGFzaA"), Err(HashError::InvalidDigestForType { hash_type: HashType::Argon2, .. })));
+    }
+
+    #[test]
+    fn parse_phc_params() {
+        let params = Argon2Params::parse("$argon2id$m=65536,t=2,p=4$c2FsdHNhbHQ$aGFzaA").unwrap();
+        assert_eq!(params.version, 0x10);
+        assert_eq!((params.memory_kib, params.iterations, params.lanes), (65536, 2, 4));
+        assert_eq!(Argon2Params::parse("$argon2id$v=19$m=65536,t=2$c2FsdHNhbHQ$aGFzaA"), None);
+        assert_eq!(Argon2Params::parse("$scrypt$ln=16,r=8,p=1$c2FsdA$aGFzaA"), None);
+    }
+}
@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+
+use crate::{DispnetHash, HashError, HashType};
+
+/// Requirements a hash must fulfill before it is used for verification.
+///
+/// The default policy allows everything, fields can be set to forbid weak hashes.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, HashPolicy, HashType};
+///
+/// fn verify_password(hash: &str, password: &[u8]) -> bool {
+///     let policy = HashPolicy {
+///         allowed_types: Some(vec![HashType::Argon2]),
+///         min_argon2_memory_kib: 4096,
+///         max_memory_kib: Some(64 * 1024),
+///         ..Default::default()
+///     };
+///     DispnetHash::verify_with_policy(hash, password, &policy).unwrap_or(false)
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HashPolicy {
+    /// Allowed hash types (`None` allows all types).
+    pub allowed_types: Option<Vec<HashType>>,
+    /// Minimal output length in bytes (4 for CRC, the decoded hash of the PHC string for Argon2).
+    pub min_digest_length: usize,
+    pub min_argon2_memory_kib: u32,
+    pub min_argon2_iterations: u32,
+    pub min_argon2_lanes: u32,
+    /// Maximal Argon2 memory (`None` allows any memory). Protects a verifying server from
+    /// untrusted hashes whose parameters would allocate gigabytes.
+    pub max_memory_kib: Option<u32>,
+}
+
+/// Reason why a hash doesn't fulfill a [`HashPolicy`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PolicyViolation {
+    HashType { hash_type: HashType },
+    DigestLength { length: usize, min_length: usize },
+    Argon2Parameters,
+    Argon2Memory { memory_kib: u32, min_memory_kib: u32 },
+    Argon2MemoryLimit { memory_kib: u32, max_memory_kib: u32 },
+    Argon2Iterations { iterations: u32, min_iterations: u32 },
+    Argon2Lanes { lanes: u32, min_lanes: u32 },
+}
+
+impl HashPolicy {
+    /// Check if the hash fulfills the policy.
+    pub fn check(&self, hash: &DispnetHash) -> Result<(), HashError> {
+        self.check_violation(hash)
+            .map_err(|violation| HashError::PolicyViolation { violation })
+    }
+
+    fn check_violation(&self, hash: &DispnetHash) -> Result<(), PolicyViolation> {
+        if let Some(allowed_types) = &self.allowed_types {
+            if !allowed_types.contains(&hash.hash_type) {
+                return Err(PolicyViolation::HashType {
+                    hash_type: hash.hash_type,
+                });
+            }
+        }
+        // CRC digests are stored as decimal text and Argon2 as PHC string, the stored length is not the output length.
+        let length = match hash.hash_type.digest_len() {
+            Some(length) => length,
+            None => hash.argon2_output_len().ok_or(PolicyViolation::Argon2Parameters)?,
+        };
+        if length < self.min_digest_length {
+            return Err(PolicyViolation::DigestLength {
+                length,
+                min_length: self.min_digest_length,
+            });
+        }
+        if hash.hash_type == HashType::Argon2 {
+            let params = hash.argon2_params().ok_or(PolicyViolation::Argon2Parameters)?;
+            if params.memory_kib < self.min_argon2_memory_kib {
+                return Err(PolicyViolation::Argon2Memory {
+                    memory_kib: params.memory_kib,
+                    min_memory_kib: self.min_argon2_memory_kib,
+                });
+            }
+            if let Some(max_memory_kib) = self.max_memory_kib.filter(|max_memory_kib| params.memory_kib > *max_memory_kib) {
+                return Err(PolicyViolation::Argon2MemoryLimit {
+                    memory_kib: params.memory_kib,
+                    max_memory_kib,
+                });
+            }
+            if params.iterations < self.min_argon2_iterations {
+                return Err(PolicyViolation::Argon2Iterations {
+                    iterations: params.iterations,
+                    min_iterations: self.min_argon2_iterations,
+                });
+            }
+            if params.lanes < self.min_argon2_lanes {
+                return Err(PolicyViolation::Argon2Lanes {
+                    lanes: params.lanes,
+                    min_lanes: self.min_argon2_lanes,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DispnetHash {
+    /// Verify a dispnet hash string with raw value if the hash fulfills the policy.
+    /// Returns an error if the hash can't be parsed or violates the policy.
+    pub fn verify_with_policy(hash: &str, value: &[u8], policy: &HashPolicy) -> Result<bool, HashError> {
+        let hash = hash.parse::<DispnetHash>()?;
+        policy.check(&hash)?;
+        Ok(DispnetHash::verify_instance(&hash, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashPolicy, HashType, PolicyViolation};
+
+    const ARGON2_SALT_HASH: &str = "030084246172676f6e326924763d3139246d3d343039362c743d332c703d31244d54497a4e4455324e7a6724686f56354d494638596a39746b39356c467365546279554a6e393336484944586754685533637065643151";
+
+    #[test]
+    fn verify_with_policy() {
+        let policy = HashPolicy { allowed_types: Some(vec![HashType::Argon2, HashType::Blake3]), ..Default::default() };
+        assert!(DispnetHash::verify_with_policy(ARGON2_SALT_HASH, "test".as_bytes(), &policy).unwrap());
+        assert!(!DispnetHash::verify_with_policy(ARGON2_SALT_HASH, "other".as_bytes(), &policy).unwrap());
+        assert!(matches!(
+            DispnetHash::verify_with_policy("02001032323538363632303830", "test".as_bytes(), &policy),
+            Err(HashError::PolicyViolation { violation: PolicyViolation::HashType { hash_type: HashType::CRC } })
+        ));
+        assert!(DispnetHash::verify_with_policy("02001032323538363632303830", "test".as_bytes(), &HashPolicy::default()).unwrap());
+    }
+
+    #[test]
+    fn policy_minimums() {
+        let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        let digest_policy = HashPolicy { min_digest_length: 32, ..Default::default() };
+        assert!(matches!(digest_policy.check(&crc_hash), Err(HashError::PolicyViolation { violation: PolicyViolation::DigestLength { length: 4, min_length: 32 } })));
+        assert!(digest_policy.check(&DispnetHash::new("test".as_bytes())).is_ok());
+
+        let argon2_hash = ARGON2_SALT_HASH.parse::<DispnetHash>().unwrap();
+        let argon2_policy = HashPolicy { min_argon2_memory_kib: 4096, min_argon2_iterations: 3, min_argon2_lanes: 1, ..Default::default() };
+        assert!(argon2_policy.check(&argon2_hash).is_ok());
+        let memory_policy = HashPolicy { min_argon2_memory_kib: 19456, ..Default::default() };
+        assert!(matches!(memory_policy.check(&argon2_hash), Err(HashError::PolicyViolation { violation: PolicyViolation::Argon2Memory { memory_kib: 4096, .. } })));
+        let memory_limit_policy = HashPolicy { max_memory_kib: Some(1024), ..Default::default() };
+        assert!(matches!(memory_limit_policy.check(&argon2_hash), Err(HashError::PolicyViolation { violation: PolicyViolation::Argon2MemoryLimit { memory_kib: 4096, max_memory_kib: 1024 } })));
+        assert!(HashPolicy { max_memory_kib: Some(4096), ..Default::default() }.check(&argon2_hash).is_ok());
+        let iterations_policy = HashPolicy { min_argon2_iterations: 4, ..Default::default() };
+        assert!(matches!(iterations_policy.check(&argon2_hash), Err(HashError::PolicyViolation { violation: PolicyViolation::Argon2Iterations { .. } })));
+        // The stored digest length is the length of the PHC string (84), the Argon2 output has 32 bytes.
+        assert!(HashPolicy { min_digest_length: 32, ..Default::default() }.check(&argon2_hash).is_ok());
+        assert!(matches!(
+            HashPolicy { min_digest_length: 64, ..Default::default() }.check(&argon2_hash),
+            Err(HashError::PolicyViolation { violation: PolicyViolation::DigestLength { length: 32, min_length: 64 } })
+        ));
+    }
+}
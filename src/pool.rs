@@ -0,0 +1,114 @@
+use std::sync::Mutex;
+
+use crate::{DispnetHash, HashConfig, HashType};
+
+/// Pool of reusable buffers for creating many hashes without allocating for every call.
+///
+/// Hashes created by the pool are regular [`DispnetHash`] values. Hand them back with
+/// [`HashBufferPool::recycle`] once they are no longer needed, their buffers are then
+/// used for the next hash. The pool keeps at most `max_buffers` buffers.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{HashBufferPool, HashType};
+///
+/// fn hash_payloads(payloads: &[&[u8]]) -> usize {
+///     let pool = HashBufferPool::new(64);
+///     let mut matches = 0;
+///     for payload in payloads {
+///         let dispnet_hash = pool.create(HashType::Blake3, payload, None);
+///         if dispnet_hash.digest_encoded % 2 == 0 {
+///             matches += 1;
+///         }
+///         pool.recycle(dispnet_hash);
+///     }
+///     matches
+/// }
+/// ```
+#[derive(Debug)]
+pub struct HashBufferPool {
+    buffers: Mutex<Vec<(Vec<u8>, String)>>,
+    max_buffers: usize,
+}
+
+impl HashBufferPool {
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::with_capacity(max_buffers)),
+            max_buffers,
+        }
+    }
+
+    /// Create a hash (see [`DispnetHash::create`]) with buffers from the pool.
+    pub fn create(&self, hash_type: HashType, value: &[u8], config: Option<HashConfig>) -> DispnetHash {
+        let hash_type = hash_type.configured(config.as_ref());
+        let (mut digest_value, mut display_value) = self.take();
+        DispnetHash::digest_into(hash_type, value, config.as_ref(), &mut digest_value);
+        DispnetHash::write_value(hash_type, &digest_value, &mut display_value);
+        DispnetHash {
+            hash_type,
+            digest_length: digest_value.len(),
+            digest_encoded: DispnetHash::encoded_u64(&digest_value),
+            digest_value,
+            value: display_value,
+        }
+    }
+
+    /// Return the buffers of a hash to the pool.
+    pub fn recycle(&self, hash: DispnetHash) {
+        if let Ok(mut buffers) = self.buffers.lock() {
+            if buffers.len() < self.max_buffers {
+                buffers.push((hash.digest_value, hash.value));
+            }
+        }
+    }
+
+    /// Number of buffers ready for reuse.
+    pub fn available(&self) -> usize {
+        self.buffers.lock().map_or(0, |buffers| buffers.len())
+    }
+
+    fn take(&self) -> (Vec<u8>, String) {
+        let buffers = self.buffers.lock().ok().and_then(|mut buffers| buffers.pop());
+        match buffers {
+            Some((mut digest_value, mut display_value)) => {
+                digest_value.clear();
+                display_value.clear();
+                (digest_value, display_value)
+            }
+            None => (Vec::new(), String::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CrcVariant, DispnetHash, HashBufferPool, HashConfig, HashType};
+
+    #[test]
+    fn pooled_hashes() {
+        let pool = HashBufferPool::new(1);
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::SimHash, HashType::GitSha1, HashType::Blake3] {
+            let pooled_hash = pool.create(hash_type, "test".as_bytes(), None);
+            let hash = DispnetHash::create(hash_type, "test".as_bytes(), None);
+            assert_eq!(pooled_hash.to_string(), hash.to_string());
+            assert_eq!(pooled_hash.digest_encoded, hash.digest_encoded);
+            pool.recycle(pooled_hash);
+        }
+        let salt_hash = pool.create(HashType::Argon2, "test".as_bytes(), Some(HashConfig::default().with_salt(*b"12345678")));
+        assert!(DispnetHash::verify_instance(&salt_hash, "test".as_bytes()));
+        let ieee_hash = pool.create(HashType::CRC, "test".as_bytes(), Some(HashConfig::default().with_crc_variant(CrcVariant::Ieee)));
+        assert_eq!(ieee_hash.hash_type, HashType::CrcIeee);
+    }
+
+    #[test]
+    fn reuse_buffers() {
+        let pool = HashBufferPool::new(1);
+        pool.recycle(pool.create(HashType::Blake3, "test".as_bytes(), None));
+        pool.recycle(pool.create(HashType::Blake3, "test".as_bytes(), None));
+        assert_eq!(pool.available(), 1);
+        let hash = pool.create(HashType::Blake3, "test".as_bytes(), None);
+        assert_eq!(pool.available(), 0);
+        assert!(hash.value.capacity() >= 70);
+    }
+}
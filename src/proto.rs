@@ -0,0 +1,85 @@
+use crate::{DispnetHash, HashError, HashType};
+
+/// Protobuf message of a dispnet hash (`proto/dispnet_hash.proto`).
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, DispnetHashProto};
+/// use prost::Message;
+///
+/// fn encode_hash() {
+///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+///     let bytes = DispnetHashProto::from(&dispnet_hash).encode_to_vec();
+///     let message = DispnetHashProto::decode(bytes.as_slice()).unwrap();
+///     assert_eq!(DispnetHash::try_from(message).unwrap(), dispnet_hash);
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DispnetHashProto {
+    #[prost(uint32, tag = "1")]
+    pub hash_type: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub digest: Vec<u8>,
+}
+
+impl From<&DispnetHash> for DispnetHashProto {
+    fn from(hash: &DispnetHash) -> Self {
+        Self {
+            hash_type: hash.hash_type.code() as u32,
+            digest: hash.digest_value.clone(),
+        }
+    }
+}
+
+impl From<DispnetHash> for DispnetHashProto {
+    fn from(hash: DispnetHash) -> Self {
+        Self {
+            hash_type: hash.hash_type.code() as u32,
+            digest: hash.digest_value,
+        }
+    }
+}
+
+impl TryFrom<DispnetHashProto> for DispnetHash {
+    type Error = HashError;
+
+    fn try_from(message: DispnetHashProto) -> Result<Self, HashError> {
+        let hash_type = u8::try_from(message.hash_type)
+            .ok()
+            .and_then(HashType::from_code)
+            .ok_or(HashError::UnknownHashType {
+                type_code: message.hash_type.min(u8::MAX as u32) as u8,
+            })?;
+        DispnetHash::from_decoded_digest(hash_type, message.digest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use crate::{DispnetHash, DispnetHashProto, HashError, HashType};
+
+    #[test]
+    fn proto_round_trip() {
+        let dispnet_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        let bytes = DispnetHashProto::from(dispnet_hash.clone()).encode_to_vec();
+        assert_eq!(bytes, [[8, 2, 18, 10].as_slice(), b"2258662080"].concat());
+        let message = DispnetHashProto::decode(bytes.as_slice()).unwrap();
+        assert_eq!(DispnetHash::try_from(message).unwrap(), dispnet_hash);
+    }
+
+    #[test]
+    fn proto_unknown_type() {
+        let message = DispnetHashProto { hash_type: 300, digest: vec![1, 2] };
+        assert!(matches!(DispnetHash::try_from(message), Err(HashError::UnknownHashType { type_code: 255 })));
+    }
+
+    #[test]
+    fn proto_digest_must_fit_type() {
+        let message = DispnetHashProto { hash_type: 1, digest: vec![1, 2, 3] };
+        assert!(matches!(DispnetHash::try_from(message), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 3 })));
+        let message = DispnetHashProto { hash_type: 2, digest: vec![0xff] };
+        assert!(matches!(DispnetHash::try_from(message), Err(HashError::InvalidDigestForType { hash_type: HashType::CRC, length: 1 })));
+    }
+}
@@ -0,0 +1,58 @@
+use base64::{prelude::BASE64_STANDARD_NO_PAD, Engine};
+
+use crate::{DispnetHash, HashType};
+
+impl DispnetHash {
+    /// Structurally valid hash with a random digest, meant as placeholder in tests.
+    ///
+    /// CRC hashes get a random checksum, Argon2 hashes a PHC string with the default parameters
+    /// and random salt and hash bytes.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn placeholder() {
+    ///     let dispnet_hash = DispnetHash::random(HashType::Blake3);
+    ///     assert!(DispnetHash::is_valid(&dispnet_hash.to_string()));
+    /// }
+    /// ```
+    pub fn random(hash_type: HashType) -> Self {
+        let digest_value = match hash_type {
+            HashType::Argon2 => {
+                let (mut salt, mut hash) = ([0u8; 16], [0u8; 32]);
+                rand::fill(&mut salt[..]);
+                rand::fill(&mut hash[..]);
+                format!(
+                    "$argon2i$v=19$m=4096,t=3,p=1${}${}",
+                    BASE64_STANDARD_NO_PAD.encode(salt),
+                    BASE64_STANDARD_NO_PAD.encode(hash)
+                )
+                .into_bytes()
+            }
+            _ if hash_type.crc_variant().is_some() => rand::random::<u32>().to_string().into_bytes(),
+            _ => {
+                let mut digest_value = vec![0; hash_type.digest_len().unwrap_or_default()];
+                rand::fill(&mut digest_value[..]);
+                digest_value
+            }
+        };
+        DispnetHash::from_digest(hash_type, digest_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashType};
+
+    #[test]
+    fn random_hashes() {
+        for hash_type in [HashType::Blake3, HashType::CRC, HashType::Argon2, HashType::SimHash, HashType::GitSha1, HashType::CrcKoopman] {
+            let random_hash = DispnetHash::random(hash_type);
+            let parsed_hash = random_hash.to_string().parse::<DispnetHash>().unwrap();
+            assert_eq!(parsed_hash, random_hash);
+            assert_eq!(parsed_hash.hash_type, hash_type);
+        }
+        assert!(DispnetHash::random(HashType::Argon2).argon2_params().is_some());
+        assert_ne!(DispnetHash::random(HashType::Blake3), DispnetHash::random(HashType::Blake3));
+    }
+}
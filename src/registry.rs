@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::{DispnetHash, HashError};
+
+/// Maps the 64-bit `digest_encoded` handles back to the full hashes.
+///
+/// `digest_encoded` is only a projection of the last 8 digest bytes, different hashes can share
+/// the same handle. The registry rejects a hash whose handle is already used by another hash.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, IdRegistry};
+///
+/// fn register() {
+///     let mut registry = IdRegistry::default();
+///     let dispnet_hash = DispnetHash::new("test".as_bytes());
+///     let handle = registry.register(&dispnet_hash).unwrap();
+///     assert_eq!(registry.get(handle), Some(&dispnet_hash));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct IdRegistry {
+    hashes: HashMap<u64, DispnetHash>,
+}
+
+impl IdRegistry {
+    /// Register the hash and return its handle.
+    /// Registering the same hash again returns the same handle.
+    pub fn register(&mut self, hash: &DispnetHash) -> Result<u64, HashError> {
+        let handle = hash.digest_encoded;
+        match self.hashes.get(&handle) {
+            Some(existing) if existing != hash => Err(HashError::HandleCollision {
+                handle,
+                existing_hash: existing.to_string(),
+                hash: hash.to_string(),
+            }),
+            Some(_) => Ok(handle),
+            None => {
+                self.hashes.insert(handle, hash.clone());
+                Ok(handle)
+            }
+        }
+    }
+
+    /// Full hash of a handle.
+    pub fn get(&self, handle: u64) -> Option<&DispnetHash> {
+        self.hashes.get(&handle)
+    }
+
+    /// Remove the handle and return its hash.
+    pub fn remove(&mut self, handle: u64) -> Option<DispnetHash> {
+        self.hashes.remove(&handle)
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType, IdRegistry};
+
+    #[test]
+    fn register() {
+        let mut registry = IdRegistry::default();
+        let dispnet_hash = DispnetHash::new("test".as_bytes());
+        let handle = registry.register(&dispnet_hash).unwrap();
+        assert_eq!(handle, dispnet_hash.digest_encoded);
+        assert_eq!(registry.register(&dispnet_hash).unwrap(), handle);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.remove(handle), Some(dispnet_hash));
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn handle_collision() {
+        let mut registry = IdRegistry::default();
+        let blake3_hash = DispnetHash::from_raw_digest(HashType::Blake3, &[7u8; 32]).unwrap();
+        let mut other_digest = [7u8; 32];
+        other_digest[0] = 8;
+        let other_hash = DispnetHash::from_raw_digest(HashType::Blake3, &other_digest).unwrap();
+        let handle = registry.register(&blake3_hash).unwrap();
+        assert!(matches!(
+            registry.register(&other_hash),
+            Err(HashError::HandleCollision { handle: collision_handle, .. }) if collision_handle == handle
+        ));
+        assert_eq!(registry.get(handle), Some(&blake3_hash));
+    }
+}
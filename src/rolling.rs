@@ -0,0 +1,149 @@
+use std::sync::OnceLock;
+
+use crate::splitmix64;
+
+const BUZHASH_SEED: u64 = 0x6275_7a68_6173_6831;
+
+fn buzhash_table() -> &'static [u64; 256] {
+    static TABLE: OnceLock<[u64; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut state = BUZHASH_SEED;
+        let mut table = [0u64; 256];
+        for value in table.iter_mut() {
+            *value = splitmix64(&mut state);
+        }
+        table
+    })
+}
+
+/// Rolling hash (buzhash) over a sliding window of bytes.
+///
+/// The hash of the window is updated in constant time per byte, which makes it usable
+/// for content defined chunk boundaries and substring search. It is not a cryptographic hash.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::RollingHash;
+///
+/// fn chunk_boundaries() {
+///     let data = vec![7u8; 4096];
+///     let mut rolling_hash = RollingHash::new(48);
+///     let boundaries: Vec<usize> = data
+///         .iter()
+///         .enumerate()
+///         .filter(|(_, b)| rolling_hash.update(**b) & 0x0fff == 0)
+///         .map(|(i, _)| i)
+///         .collect();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    window: Vec<u8>,
+    window_size: usize,
+    position: usize,
+    filled: usize,
+    value: u64,
+}
+
+impl RollingHash {
+    /// Create a rolling hash for windows of `window_size` bytes (at least 1).
+    pub fn new(window_size: usize) -> Self {
+        let window_size = window_size.max(1);
+        Self {
+            window: vec![0; window_size],
+            window_size,
+            position: 0,
+            filled: 0,
+            value: 0,
+        }
+    }
+
+    /// Hash of a complete window, equal to the value after rolling over exactly these bytes.
+    pub fn hash_window(window: &[u8]) -> u64 {
+        let table = buzhash_table();
+        window
+            .iter()
+            .fold(0u64, |value, b| value.rotate_left(1) ^ table[*b as usize])
+    }
+
+    /// Push a byte into the window (dropping the oldest byte once the window is full)
+    /// and return the hash of the current window.
+    pub fn update(&mut self, byte: u8) -> u64 {
+        let table = buzhash_table();
+        self.value = self.value.rotate_left(1) ^ table[byte as usize];
+        if self.filled == self.window_size {
+            let removed = self.window[self.position];
+            self.value ^= table[removed as usize].rotate_left((self.window_size % 64) as u32);
+        } else {
+            self.filled += 1;
+        }
+        self.window[self.position] = byte;
+        self.position = (self.position + 1) % self.window_size;
+        self.value
+    }
+
+    /// Hash of the current window.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// True if the window contains `window_size` bytes.
+    pub fn is_full(&self) -> bool {
+        self.filled == self.window_size
+    }
+
+    /// Clear the window.
+    pub fn reset(&mut self) {
+        self.position = 0;
+        self.filled = 0;
+        self.value = 0;
+    }
+
+    /// Find the first position of `needle` in `haystack` (Rabin-Karp search with the rolling hash).
+    pub fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        let needle_hash = RollingHash::hash_window(needle);
+        let mut rolling_hash = RollingHash::new(needle.len());
+        haystack.iter().enumerate().find_map(|(i, b)| {
+            let value = rolling_hash.update(*b);
+            let start = (i + 1).checked_sub(needle.len())?;
+            if value == needle_hash && &haystack[start..=i] == needle {
+                Some(start)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RollingHash;
+
+    #[test]
+    fn rolling_hash_window() {
+        let data = b"the quick brown fox jumps over the lazy dog";
+        let mut rolling_hash = RollingHash::new(8);
+        for (i, b) in data.iter().enumerate() {
+            let value = rolling_hash.update(*b);
+            if i >= 7 {
+                assert!(rolling_hash.is_full());
+                assert_eq!(value, RollingHash::hash_window(&data[(i - 7)..=i]));
+            }
+        }
+        rolling_hash.reset();
+        assert!(!rolling_hash.is_full());
+        assert_eq!(rolling_hash.update(b't'), RollingHash::hash_window(b"t"));
+    }
+
+    #[test]
+    fn rolling_hash_find() {
+        let data = b"the quick brown fox jumps over the lazy dog";
+        assert_eq!(RollingHash::find(data, b"fox"), Some(16));
+        assert_eq!(RollingHash::find(data, b"the"), Some(0));
+        assert_eq!(RollingHash::find(data, b"cat"), None);
+        assert_eq!(RollingHash::find(b"do", b"dog"), None);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{collections::HashMap, io::Read};
+
+use crate::{DispnetHash, HashType, RollingHash};
+
+/// Signature of a file for rsync style delta transfers (see [`delta`]).
+///
+/// The file is split into blocks of `block_size` bytes (the last block can be shorter),
+/// each block has a weak rolling hash ([`RollingHash`]) and a strong Blake3 hash.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub block_size: usize,
+    pub blocks: Vec<BlockSignature>,
+}
+
+/// Weak and strong hash of a signature block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockSignature {
+    pub weak_hash: u64,
+    pub strong_hash: DispnetHash,
+    pub length: usize,
+}
+
+/// Changes which turn the file of a [`Signature`] into a new file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub block_size: usize,
+    pub operations: Vec<DeltaOperation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeltaOperation {
+    /// Copy the block with the index from the old file.
+    Copy { block_index: usize },
+    /// New bytes which are not in the old file.
+    Literal(Vec<u8>),
+}
+
+/// Create the signature of the content with blocks of `block_size` bytes (at least 1).
+/// # Usage
+/// ```
+/// use dispnet_hash::{delta, signature};
+///
+/// fn sync(old_file: &[u8], new_file: &[u8]) {
+///     let old_signature = signature(old_file, 1024).unwrap();
+///     let delta = delta(&old_signature, new_file).unwrap();
+///     assert_eq!(delta.apply(old_file), new_file);
+/// }
+/// ```
+pub fn signature(mut reader: impl Read, block_size: usize) -> std::io::Result<Signature> {
+    let block_size = block_size.max(1);
+    let mut blocks = Vec::new();
+    let mut block = vec![0u8; block_size];
+    loop {
+        let length = read_block(&mut reader, &mut block)?;
+        if length == 0 {
+            break;
+        }
+        blocks.push(BlockSignature {
+            weak_hash: RollingHash::hash_window(&block[..length]),
+            strong_hash: DispnetHash::create(HashType::Blake3, &block[..length], None),
+            length,
+        });
+    }
+    Ok(Signature { block_size, blocks })
+}
+
+/// Compute the delta of the new content against the signature of the old content.
+///
+/// Blocks of the old content are found at any offset of the new content,
+/// everything in between is sent as literal bytes.
+pub fn delta(signature: &Signature, mut new_reader: impl Read) -> std::io::Result<Delta> {
+    let mut data = Vec::new();
+    new_reader.read_to_end(&mut data)?;
+    let block_size = signature.block_size;
+    let mut block_indices: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (block_index, block) in signature.blocks.iter().enumerate() {
+        if block.length == block_size {
+            block_indices.entry(block.weak_hash).or_default().push(block_index);
+        }
+    }
+    let find_block = |weak_hash: u64, window: &[u8]| {
+        let candidates = block_indices.get(&weak_hash)?;
+        let strong_hash = DispnetHash::create(HashType::Blake3, window, None);
+        candidates
+            .iter()
+            .copied()
+            .find(|block_index| signature.blocks[*block_index].strong_hash == strong_hash)
+    };
+
+    let mut operations = Vec::new();
+    let mut literal_start = 0;
+    let mut rolling_hash = RollingHash::new(block_size);
+    for end in 1..=data.len() {
+        rolling_hash.update(data[end - 1]);
+        if !rolling_hash.is_full() {
+            continue;
+        }
+        let start = end - block_size;
+        if let Some(block_index) = find_block(rolling_hash.value(), &data[start..end]) {
+            push_literal(&mut operations, &data[literal_start..start]);
+            operations.push(DeltaOperation::Copy { block_index });
+            literal_start = end;
+            rolling_hash.reset();
+        }
+    }
+
+    // The shorter last block can only match at the end.
+    let mut literal_end = data.len();
+    if let Some(last_block) = signature.blocks.last().filter(|block| block.length < block_size) {
+        let tail_start = data.len().saturating_sub(last_block.length);
+        if data.len() - literal_start >= last_block.length
+            && DispnetHash::create(HashType::Blake3, &data[tail_start..], None) == last_block.strong_hash
+        {
+            literal_end = tail_start;
+        }
+    }
+    push_literal(&mut operations, &data[literal_start..literal_end]);
+    if literal_end < data.len() {
+        operations.push(DeltaOperation::Copy {
+            block_index: signature.blocks.len() - 1,
+        });
+    }
+    Ok(Delta { block_size, operations })
+}
+
+impl Delta {
+    /// Apply the delta to the old content and return the new content.
+    /// Copies of blocks outside of the old content are skipped.
+    pub fn apply(&self, old: &[u8]) -> Vec<u8> {
+        let mut new = Vec::with_capacity(old.len());
+        for operation in &self.operations {
+            match operation {
+                DeltaOperation::Copy { block_index } => {
+                    let start = (block_index * self.block_size).min(old.len());
+                    let end = (start + self.block_size).min(old.len());
+                    new.extend_from_slice(&old[start..end]);
+                }
+                DeltaOperation::Literal(bytes) => new.extend_from_slice(bytes),
+            }
+        }
+        new
+    }
+
+    /// Number of literal bytes which have to be transferred.
+    pub fn literal_length(&self) -> usize {
+        self.operations
+            .iter()
+            .map(|operation| match operation {
+                DeltaOperation::Literal(bytes) => bytes.len(),
+                DeltaOperation::Copy { .. } => 0,
+            })
+            .sum()
+    }
+}
+
+fn push_literal(operations: &mut Vec<DeltaOperation>, bytes: &[u8]) {
+    if !bytes.is_empty() {
+        operations.push(DeltaOperation::Literal(bytes.to_vec()));
+    }
+}
+
+/// Fill the block as far as possible, returns the number of bytes read (0 at the end).
+fn read_block(reader: &mut impl Read, block: &mut [u8]) -> std::io::Result<usize> {
+    let mut length = 0;
+    while length < block.len() {
+        match reader.read(&mut block[length..]) {
+            Ok(0) => break,
+            Ok(read) => length += read,
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{delta, signature, DeltaOperation};
+
+    fn content(length: usize) -> Vec<u8> {
+        (0..length).map(|i| ((i * 7919) % 251) as u8).collect()
+    }
+
+    #[test]
+    fn delta_of_changed_file() {
+        let old = content(10_000);
+        let mut new = old.clone();
+        new.splice(4_000..4_000, b"inserted".iter().copied());
+        new[9_000] ^= 0xff;
+        let old_signature = signature(old.as_slice(), 512).unwrap();
+        assert_eq!(old_signature.blocks.len(), 20);
+        let delta = delta(&old_signature, new.as_slice()).unwrap();
+        assert_eq!(delta.apply(&old), new);
+        // The insertion and the changed byte each cost at most a block of literals.
+        assert!(delta.literal_length() <= 2 * 512 + 8);
+        assert_eq!(delta.operations.last(), Some(&DeltaOperation::Copy { block_index: 19 }));
+    }
+
+    #[test]
+    fn delta_edge_cases() {
+        let old = content(1_000);
+        let old_signature = signature(old.as_slice(), 256).unwrap();
+        let unchanged = delta(&old_signature, old.as_slice()).unwrap();
+        assert_eq!(unchanged.literal_length(), 0);
+        assert_eq!(unchanged.operations.len(), 4);
+
+        let empty = delta(&old_signature, &[][..]).unwrap();
+        assert!(empty.operations.is_empty());
+        let new = content(100);
+        let from_empty = delta(&signature(&[][..], 256).unwrap(), new.as_slice()).unwrap();
+        assert_eq!(from_empty.operations, vec![DeltaOperation::Literal(new.clone())]);
+        assert_eq!(from_empty.apply(&[]), new);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{DispnetHash, HashType};
+
+const KNOWN_ANSWER_INPUT: &[u8] = b"test";
+
+const KNOWN_ANSWERS: &[(HashType, &str)] = &[
+    (HashType::Blake3, "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215"),
+    (HashType::CRC, "02001032323538363632303830"),
+    (HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e5568716147637924464d4f7a6f46647754464676397a31435a485751684b7a2f63696f754c55427571494a54756a574d375338"),
+    (HashType::SimHash, "040008f5b1e32f50603215"),
+    (HashType::GitSha1, "05002030d74d258442c7c65512eafab474568dd706c430"),
+    (HashType::CrcIeee, "06001033363332323333393936"),
+    (HashType::CrcKoopman, "07001031353437323833323330"),
+];
+
+/// Result of the known-answer test of one hash type.
+#[derive(Debug)]
+pub struct SelfTestResult {
+    pub hash_type: HashType,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl SelfTestResult {
+    /// True if the computed hash matches the known answer.
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Report of the known-answer tests of all hash types.
+#[derive(Debug)]
+pub struct SelfTestReport {
+    pub results: Vec<SelfTestResult>,
+}
+
+impl SelfTestReport {
+    /// True if all hash types passed.
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(SelfTestResult::passed)
+    }
+
+    /// Results of the hash types which failed.
+    pub fn failures(&self) -> impl Iterator<Item = &SelfTestResult> {
+        self.results.iter().filter(|result| !result.passed())
+    }
+}
+
+/// Run the built-in known-answer tests for every hash type (e.g. at startup for runtime algorithm validation).
+/// # Usage
+/// ```
+/// fn validate_algorithms() {
+///     let report = dispnet_hash::self_test();
+///     assert!(report.passed());
+/// }
+/// ```
+pub fn self_test() -> SelfTestReport {
+    let results = KNOWN_ANSWERS
+        .iter()
+        .map(|(hash_type, expected)| SelfTestResult {
+            hash_type: *hash_type,
+            expected: (*expected).to_owned(),
+            actual: DispnetHash::create(*hash_type, KNOWN_ANSWER_INPUT, None).to_string(),
+        })
+        .collect();
+    SelfTestReport { results }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self_test, HashType, SelfTestResult};
+
+    #[test]
+    fn self_test_report() {
+        let report = self_test();
+        assert_eq!(report.results.len(), 7);
+        assert!(report.passed(), "{:?}", report.failures().collect::<Vec<_>>());
+        let failed_result = SelfTestResult { hash_type: HashType::CRC, expected: "02".to_owned(), actual: "03".to_owned() };
+        assert!(!failed_result.passed());
+    }
+}
@@ -0,0 +1,208 @@
+//! Serde formats for [`DispnetHash`] fields.
+//!
+//! `DispnetHash` serializes to the display format in human readable formats (JSON, TOML, ...)
+//! and to the compact binary format (see [`DispnetHash::to_bytes`]) otherwise.
+//! The modules below force one format per field with `#[serde(with = "...")]`,
+//! the marker types do the same with `serde_with::serde_as` (feature `serde_with`).
+//!
+//! # Usage
+//! ```
+//! use dispnet_hash::{serde_formats, DispnetHash};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "serde_formats::as_base64")]
+//!     hash: DispnetHash,
+//! }
+//! ```
+use std::fmt;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::DispnetHash;
+
+impl Serialize for DispnetHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.value)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DispnetHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HashVisitor)
+        } else {
+            deserializer.deserialize_bytes(HashVisitor)
+        }
+    }
+}
+
+/// Accepts the display format as string and the binary format as bytes.
+struct HashVisitor;
+
+impl<'de> Visitor<'de> for HashVisitor {
+    type Value = DispnetHash;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a dispnet hash")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<DispnetHash, E> {
+        value.parse::<DispnetHash>().map_err(|error| E::custom(format!("{:?}", error)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<DispnetHash, E> {
+        DispnetHash::from_bytes(value).map_err(|error| E::custom(format!("{:?}", error)))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<DispnetHash, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+/// Display format (type, digest length and hex digest) as string.
+pub mod as_hex {
+    use serde::{Deserializer, Serializer};
+
+    use crate::DispnetHash;
+
+    pub fn serialize<S: Serializer>(hash: &DispnetHash, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hash.value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DispnetHash, D::Error> {
+        deserializer.deserialize_str(super::HashVisitor)
+    }
+}
+
+/// Binary format (see [`DispnetHash::to_bytes`]) as base64 string.
+pub mod as_base64 {
+    use base64::{prelude::BASE64_STANDARD, Engine};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::DispnetHash;
+
+    pub fn serialize<S: Serializer>(hash: &DispnetHash, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(hash.to_bytes()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DispnetHash, D::Error> {
+        let encoded = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        super::from_base64(&encoded).map_err(de::Error::custom)
+    }
+}
+
+/// Binary format (see [`DispnetHash::to_bytes`]) as bytes.
+pub mod as_bytes {
+    use serde::{Deserializer, Serializer};
+
+    use crate::DispnetHash;
+
+    pub fn serialize<S: Serializer>(hash: &DispnetHash, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&hash.to_bytes())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DispnetHash, D::Error> {
+        deserializer.deserialize_bytes(super::HashVisitor)
+    }
+}
+
+fn from_base64(encoded: &str) -> Result<DispnetHash, String> {
+    let bytes = BASE64_STANDARD.decode(encoded).map_err(|error| error.to_string())?;
+    DispnetHash::from_bytes(&bytes).map_err(|error| format!("{:?}", error))
+}
+
+/// `serde_as` marker for [`as_hex`].
+#[cfg(feature = "serde_with")]
+pub struct Hex;
+
+/// `serde_as` marker for [`as_base64`].
+#[cfg(feature = "serde_with")]
+pub struct Base64;
+
+/// `serde_as` marker for [`as_bytes`].
+#[cfg(feature = "serde_with")]
+pub struct Bytes;
+
+#[cfg(feature = "serde_with")]
+macro_rules! impl_serde_as {
+    ($marker:ty, $module:ident) => {
+        impl serde_with::SerializeAs<DispnetHash> for $marker {
+            fn serialize_as<S: Serializer>(hash: &DispnetHash, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(hash, serializer)
+            }
+        }
+
+        impl<'de> serde_with::DeserializeAs<'de, DispnetHash> for $marker {
+            fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DispnetHash, D::Error> {
+                $module::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde_with")]
+impl_serde_as!(Hex, as_hex);
+#[cfg(feature = "serde_with")]
+impl_serde_as!(Base64, as_base64);
+#[cfg(feature = "serde_with")]
+impl_serde_as!(Bytes, as_bytes);
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{serde_formats, DispnetHash, HashType};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Block {
+        hash: DispnetHash,
+        #[serde(with = "serde_formats::as_hex")]
+        hex: DispnetHash,
+        #[serde(with = "serde_formats::as_base64")]
+        base64: DispnetHash,
+        #[serde(with = "serde_formats::as_bytes")]
+        bytes: DispnetHash,
+    }
+
+    #[test]
+    fn serde_formats() {
+        let hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+        let block = Block { hash: hash.clone(), hex: hash.clone(), base64: hash.clone(), bytes: hash };
+        let json = serde_json::to_string(&block).unwrap();
+        assert_eq!(
+            json,
+            r#"{"hash":"02001032323538363632303830","hex":"02001032323538363632303830","base64":"AgAKMjI1ODY2MjA4MA==","bytes":[2,0,10,50,50,53,56,54,54,50,48,56,48]}"#
+        );
+        assert_eq!(serde_json::from_str::<Block>(&json).unwrap(), block);
+        assert!(serde_json::from_str::<DispnetHash>(r#""0200103232353836363230""#).is_err());
+    }
+
+    #[cfg(feature = "serde_with")]
+    #[test]
+    fn serde_as_markers() {
+        #[serde_with::serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Hashes {
+            #[serde_as(as = "Vec<serde_formats::Base64>")]
+            hashes: Vec<DispnetHash>,
+        }
+        let hashes = Hashes { hashes: vec![DispnetHash::create(HashType::CRC, "test".as_bytes(), None)] };
+        let json = serde_json::to_string(&hashes).unwrap();
+        assert_eq!(json, r#"{"hashes":["AgAKMjI1ODY2MjA4MA=="]}"#);
+        assert_eq!(serde_json::from_str::<Hashes>(&json).unwrap(), hashes);
+    }
+}
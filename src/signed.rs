@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey, SIGNATURE_LENGTH};
+
+use crate::{DispnetHash, HashError};
+
+/// Dispnet hash with an ed25519 signature over the canonical hash bytes.
+///
+/// The canonical hash bytes are the UTF-8 bytes of the dispnet hash display format.
+///
+/// # Display format is structured as followed:
+///
+/// * First 128 characters are the signature as hex.
+/// * Then comes the dispnet hash in its display format.
+#[derive(Debug)]
+pub struct SignedDispnetHash {
+    pub hash: DispnetHash,
+    pub signature: Signature,
+}
+
+impl SignedDispnetHash {
+    /// Sign a dispnet hash with an ed25519 keypair.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, SignedDispnetHash};
+    /// use ed25519_dalek::SigningKey;
+    ///
+    /// fn sign_hash() {
+    ///     let keypair = SigningKey::from_bytes(&[7u8; 32]);
+    ///     let signed_hash = SignedDispnetHash::sign(DispnetHash::new("test".as_bytes()), &keypair);
+    ///     assert!(signed_hash.verify_signature(&keypair.verifying_key()));
+    /// }
+    /// ```
+    pub fn sign(hash: DispnetHash, keypair: &SigningKey) -> Self {
+        let signature = keypair.sign(hash.to_string().as_bytes());
+        Self { hash, signature }
+    }
+
+    /// Verify the signature of the hash with the public key of the signer.
+    pub fn verify_signature(&self, pubkey: &VerifyingKey) -> bool {
+        pubkey
+            .verify(self.hash.to_string().as_bytes(), &self.signature)
+            .is_ok()
+    }
+
+    fn parse(signed_hash_value: &str) -> Result<Self, HashError> {
+        let hex_signature_length = SIGNATURE_LENGTH * 2;
+        let (raw_signature, raw_hash) = match signed_hash_value.get(..hex_signature_length) {
+            Some(raw_signature) => (raw_signature, &signed_hash_value[hex_signature_length..]),
+            None => {
+                return Err(HashError::InvalidSignature {
+                    hex_signature: signed_hash_value.to_owned(),
+                })
+            }
+        };
+        let signature_bytes = DispnetHash::hex_to_bytes(raw_signature)
+            .and_then(|bytes| <[u8; SIGNATURE_LENGTH]>::try_from(bytes).ok());
+        if let Some(signature_bytes) = signature_bytes {
+            let hash = raw_hash.parse::<DispnetHash>()?;
+            Ok(Self {
+                hash,
+                signature: Signature::from_bytes(&signature_bytes),
+            })
+        } else {
+            Err(HashError::InvalidSignature {
+                hex_signature: raw_signature.to_owned(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for SignedDispnetHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            DispnetHash::bytes_to_hex(&self.signature.to_bytes()),
+            self.hash
+        )
+    }
+}
+
+impl FromStr for SignedDispnetHash {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        SignedDispnetHash::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::SigningKey;
+
+    use crate::{DispnetHash, HashError, SignedDispnetHash};
+
+    #[test]
+    fn sign_and_verify_hash() {
+        let keypair = SigningKey::from_bytes(&[7u8; 32]);
+        let other_keypair = SigningKey::from_bytes(&[8u8; 32]);
+        let signed_hash = SignedDispnetHash::sign(DispnetHash::new("test".as_bytes()), &keypair);
+        assert!(signed_hash.verify_signature(&keypair.verifying_key()));
+        assert!(!signed_hash.verify_signature(&other_keypair.verifying_key()));
+    }
+
+    #[test]
+    fn parse_signed_hash() {
+        let keypair = SigningKey::from_bytes(&[7u8; 32]);
+        let signed_hash = SignedDispnetHash::sign(DispnetHash::new("test".as_bytes()), &keypair);
+        let parsed_hash = signed_hash.to_string().parse::<SignedDispnetHash>().unwrap();
+        assert_eq!(parsed_hash.hash, signed_hash.hash);
+        assert_eq!(parsed_hash.signature, signed_hash.signature);
+        assert!(parsed_hash.verify_signature(&keypair.verifying_key()));
+        assert!(matches!("abcd".parse::<SignedDispnetHash>(), Err(HashError::InvalidSignature { .. })));
+    }
+}
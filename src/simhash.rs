@@ -0,0 +1,86 @@
+use crate::{DispnetHash, HashType};
+
+/// Create a SimHash fingerprint of the tokens as dispnet hash.
+///
+/// Similar token sets produce fingerprints with a small Hamming distance.
+/// Creating a hash with `HashType::SimHash` uses the whitespace separated words of the value as tokens.
+/// # Usage
+/// ```
+/// use dispnet_hash::{simhash, DispnetHash, HashType};
+///
+/// fn simhash_distance() {
+///     let simhash_1 = simhash("the quick brown fox jumps over the lazy dog".split(' '));
+///     let simhash_2 = DispnetHash::create(HashType::SimHash, "the quick brown fox jumps over the lazy cat".as_bytes(), None);
+///     let distance = simhash_1.hamming_distance(&simhash_2).unwrap();
+/// }
+/// ```
+pub fn simhash<I, T>(tokens: I) -> DispnetHash
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let hash_bytes = fingerprint(tokens).to_le_bytes();
+    DispnetHash::from_digest(HashType::SimHash, hash_bytes.to_vec())
+}
+
+pub(crate) fn fingerprint<I, T>(tokens: I) -> u64
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut weights = [0i64; 64];
+    for token in tokens {
+        let token_hash = DispnetHash::encoded_u64(blake3::hash(token.as_ref()).as_bytes());
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if token_hash & (1 << bit) != 0 {
+                *weight += 1;
+            } else {
+                *weight -= 1;
+            }
+        }
+    }
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0)
+        .fold(0u64, |fingerprint, (bit, _)| fingerprint | (1 << bit))
+}
+
+impl DispnetHash {
+    /// Count of different bits between two SimHash fingerprints.
+    /// Returns `None` if one of the hashes is not a SimHash.
+    pub fn hamming_distance(&self, other: &DispnetHash) -> Option<u32> {
+        if self.hash_type != HashType::SimHash || other.hash_type != HashType::SimHash {
+            return None;
+        }
+        Some((self.digest_encoded ^ other.digest_encoded).count_ones())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{simhash, DispnetHash, HashType};
+
+    #[test]
+    fn simhash_hash() {
+        let dispnet_hash = DispnetHash::create(HashType::SimHash, "the quick brown fox".as_bytes(), None);
+        assert_eq!(dispnet_hash.digest_length, 8);
+        assert!(dispnet_hash.to_string().starts_with("040008"));
+        assert_eq!(dispnet_hash, simhash(["the", "quick", "brown", "fox"]));
+        assert_eq!(dispnet_hash, dispnet_hash.to_string().parse::<DispnetHash>().unwrap());
+    }
+
+    #[test]
+    fn simhash_hamming_distance() {
+        let text: Vec<String> = (0..200).map(|i| format!("word{}", i)).collect();
+        let mut similar_text = text.clone();
+        similar_text[7] = "changed".to_owned();
+        let other_text: Vec<String> = (0..200).map(|i| format!("other{}", i)).collect();
+        let simhash_1 = simhash(&text);
+        assert_eq!(simhash_1.hamming_distance(&simhash(&text)), Some(0));
+        let similar_distance = simhash_1.hamming_distance(&simhash(&similar_text)).unwrap();
+        let other_distance = simhash_1.hamming_distance(&simhash(&other_text)).unwrap();
+        assert!(similar_distance < other_distance);
+        assert_eq!(simhash_1.hamming_distance(&DispnetHash::new("test".as_bytes())), None);
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use crate::{DispnetHash, HashError, HashType};
+
+const ESCAPE: u8 = 0x00;
+const ESCAPED_ZERO: u8 = 0xff;
+const TERMINATOR: u8 = 0x01;
+
+impl DispnetHash {
+    /// Byte string whose lexicographic order matches the `(type, digest)` order of the hashes.
+    ///
+    /// The sort key is structured as followed:
+    ///
+    /// * First byte is the hash type.
+    /// * Digest value as raw bytes where `00` is escaped as `00 ff`.
+    /// * Terminated by `00 01`, so the key can be followed by other key parts (e.g. in a RocksDB key).
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn sort_key() {
+    ///     let blake3_hash = DispnetHash::new("test".as_bytes());
+    ///     let crc_hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+    ///     assert!(blake3_hash.to_sort_key() < crc_hash.to_sort_key());
+    ///     assert_eq!(DispnetHash::from_sort_key(&crc_hash.to_sort_key()).unwrap(), crc_hash);
+    /// }
+    /// ```
+    pub fn to_sort_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(3 + self.digest_value.len());
+        key.push(self.hash_type.code());
+        for byte in &self.digest_value {
+            key.push(*byte);
+            if *byte == ESCAPE {
+                key.push(ESCAPED_ZERO);
+            }
+        }
+        key.push(ESCAPE);
+        key.push(TERMINATOR);
+        key
+    }
+
+    /// Create a hash from a sort key (see [`DispnetHash::to_sort_key`]).
+    /// Bytes after the terminator are rejected.
+    pub fn from_sort_key(key: &[u8]) -> Result<Self, HashError> {
+        let invalid = || HashError::InvalidDigest {
+            hex_digest: DispnetHash::bytes_to_hex(key),
+        };
+        let (type_code, escaped_digest) = key.split_first().ok_or_else(invalid)?;
+        let hash_type = HashType::from_code(*type_code)
+            .ok_or(HashError::UnknownHashType { type_code: *type_code })?;
+        let mut digest_value = Vec::with_capacity(escaped_digest.len());
+        let mut bytes = escaped_digest.iter();
+        while let Some(byte) = bytes.next() {
+            if *byte != ESCAPE {
+                digest_value.push(*byte);
+                continue;
+            }
+            match bytes.next() {
+                Some(&ESCAPED_ZERO) => digest_value.push(ESCAPE),
+                Some(&TERMINATOR) if bytes.len() == 0 => {
+                    return DispnetHash::from_decoded_digest(hash_type, digest_value);
+                }
+                _ => break,
+            }
+        }
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType};
+
+    #[test]
+    fn sort_key_order() {
+        let hashes = [
+            DispnetHash::from_raw_digest(HashType::Blake3, &[0u8; 32]).unwrap(),
+            DispnetHash::from_raw_digest(HashType::Blake3, &[[0u8; 31].as_slice(), &[1]].concat()).unwrap(),
+            DispnetHash::from_raw_digest(HashType::Blake3, &[1u8; 32]).unwrap(),
+            DispnetHash::create(HashType::CRC, "test".as_bytes(), None),
+            DispnetHash::create(HashType::SimHash, "test".as_bytes(), None),
+        ];
+        for pair in hashes.windows(2) {
+            assert!(pair[0].to_sort_key() < pair[1].to_sort_key());
+        }
+        for hash in &hashes {
+            assert_eq!(DispnetHash::from_sort_key(&hash.to_sort_key()).unwrap(), *hash);
+        }
+    }
+
+    #[test]
+    fn invalid_sort_key() {
+        let mut key = DispnetHash::new("test".as_bytes()).to_sort_key();
+        key.push(0);
+        assert!(matches!(DispnetHash::from_sort_key(&key), Err(HashError::InvalidDigest { .. })));
+        assert!(matches!(DispnetHash::from_sort_key(&[1, 2, 3]), Err(HashError::InvalidDigest { .. })));
+        assert!(matches!(DispnetHash::from_sort_key(&[]), Err(HashError::InvalidDigest { .. })));
+        assert!(matches!(DispnetHash::from_sort_key(&[9, 0, 1]), Err(HashError::UnknownHashType { type_code: 9 })));
+        assert!(matches!(DispnetHash::from_sort_key(&[1, 5, 0, 1]), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 1 })));
+    }
+}
@@ -0,0 +1,71 @@
+use std::io::{self, Read, Write};
+
+use crate::{DispnetHash, DispnetHasher, HashType};
+
+const COPY_BUFFER_LEN: usize = 64 * 1024;
+
+/// Copy the reader into the writer (like [`io::copy`]) and hash the copied bytes in the same pass.
+/// Returns the number of copied bytes and the hash of the content.
+/// # Usage
+/// ```
+/// use dispnet_hash::{copy_and_hash, DispnetHash, HashType};
+///
+/// fn ingest() {
+///     let mut stored = Vec::new();
+///     let (length, dispnet_hash) = copy_and_hash(&mut "test".as_bytes(), &mut stored, HashType::Blake3).unwrap();
+///     assert_eq!(length, 4);
+///     assert_eq!(dispnet_hash, DispnetHash::new("test".as_bytes()));
+/// }
+/// ```
+pub fn copy_and_hash<R: Read + ?Sized, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    hash_type: HashType,
+) -> io::Result<(u64, DispnetHash)> {
+    let mut hasher = DispnetHasher::new(hash_type, None);
+    let mut buffer = vec![0; COPY_BUFFER_LEN];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        writer.write_all(&buffer[..read])?;
+        hasher.update(&buffer[..read]);
+    }
+    Ok((hasher.input_length(), hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use crate::{copy_and_hash, DispnetHash, HashType};
+
+    #[test]
+    fn copy_and_hash_content() {
+        let data: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        let mut copied = Vec::new();
+        let (length, dispnet_hash) = copy_and_hash(&mut data.as_slice(), &mut copied, HashType::CRC).unwrap();
+        assert_eq!(length, data.len() as u64);
+        assert_eq!(copied, data);
+        assert_eq!(dispnet_hash, DispnetHash::create(HashType::CRC, &data, None));
+    }
+
+    #[test]
+    fn copy_and_hash_errors() {
+        struct FailingReader;
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+        assert!(copy_and_hash(&mut FailingReader, &mut Vec::new(), HashType::Blake3).is_err());
+        let mut full_buffer = [0u8; 2];
+        assert_eq!(
+            copy_and_hash(&mut "test".as_bytes(), &mut full_buffer.as_mut_slice(), HashType::Blake3).unwrap_err().kind(),
+            io::ErrorKind::WriteZero
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    fmt,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{DispnetHash, HashError};
+
+const TIMESTAMP_LENGTH: usize = 20;
+
+/// Dispnet hash with a creation timestamp and an optional expiry.
+///
+/// Timestamps are seconds since the unix epoch. The expiry 0 is reserved for "never expires".
+///
+/// # Display format is structured as followed:
+///
+/// * First 20 characters are the creation timestamp as integer with leading 0.
+/// * Then come 20 characters as integer with leading 0 which is the expiry timestamp (0 if the hash never expires).
+/// * Then comes the dispnet hash in its display format.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{DispnetHash, TimestampedDispnetHash};
+///
+/// fn timestamped_hash() {
+///     let timestamped_hash = TimestampedDispnetHash::with_timestamp(DispnetHash::new("test".as_bytes()), 1700000000, Some(1700003600)).unwrap();
+///     assert_eq!(timestamped_hash.to_string(), "00000000001700000000000000000017000036000100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct TimestampedDispnetHash {
+    pub hash: DispnetHash,
+    pub created: u64,
+    pub expires: Option<u64>,
+}
+
+impl TimestampedDispnetHash {
+    /// Create a timestamped hash which is created now and expires after `ttl` seconds (never if `None`).
+    /// Fails if the expiry would be 0 (the clock is at the unix epoch and `ttl` is 0).
+    pub fn new(hash: DispnetHash, ttl: Option<u64>) -> Result<Self, HashError> {
+        let created = unix_now();
+        Self::with_timestamp(hash, created, ttl.map(|ttl| created.saturating_add(ttl)))
+    }
+
+    /// Create a timestamped hash with explicit creation and expiry timestamps.
+    /// The expiry `Some(0)` is rejected, it would be parsed back as `None`.
+    pub fn with_timestamp(hash: DispnetHash, created: u64, expires: Option<u64>) -> Result<Self, HashError> {
+        if expires == Some(0) {
+            return Err(HashError::InvalidTimestamp {
+                raw_timestamp: format!("{:020}", 0),
+            });
+        }
+        Ok(Self {
+            hash,
+            created,
+            expires,
+        })
+    }
+
+    /// Check if the hash is expired at the given unix timestamp.
+    pub fn is_expired_at(&self, timestamp: u64) -> bool {
+        matches!(self.expires, Some(expires) if expires <= timestamp)
+    }
+
+    /// Check if the hash is expired now.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(unix_now())
+    }
+
+    fn parse(timestamped_hash_value: &str) -> Result<Self, HashError> {
+        let created = parse_timestamp(timestamped_hash_value, 0)?;
+        let expires = parse_timestamp(timestamped_hash_value, TIMESTAMP_LENGTH)?;
+        let hash = timestamped_hash_value[(TIMESTAMP_LENGTH * 2)..].parse::<DispnetHash>()?;
+        Ok(Self {
+            hash,
+            created,
+            expires: if expires == 0 { None } else { Some(expires) },
+        })
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+fn parse_timestamp(value: &str, start: usize) -> Result<u64, HashError> {
+    if let Some(raw_timestamp) = value.get(start..(start + TIMESTAMP_LENGTH)) {
+        if raw_timestamp.bytes().all(|b| b.is_ascii_digit()) {
+            if let Ok(timestamp) = raw_timestamp.parse::<u64>() {
+                return Ok(timestamp);
+            }
+        }
+    }
+    Err(HashError::InvalidTimestamp {
+        raw_timestamp: value.get(start..).unwrap_or_default().to_owned(),
+    })
+}
+
+impl fmt::Display for TimestampedDispnetHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:020}{:020}{}",
+            self.created,
+            self.expires.unwrap_or(0),
+            self.hash
+        )
+    }
+}
+
+impl FromStr for TimestampedDispnetHash {
+    type Err = HashError;
+
+    fn from_str(s: &str) -> Result<Self, HashError> {
+        TimestampedDispnetHash::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, TimestampedDispnetHash};
+
+    #[test]
+    fn timestamped_hash() {
+        let timestamped_hash = TimestampedDispnetHash::with_timestamp(DispnetHash::new("test".as_bytes()), 1700000000, Some(1700003600)).unwrap();
+        assert_eq!(timestamped_hash.to_string(), "00000000001700000000000000000017000036000100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215");
+        assert!(!timestamped_hash.is_expired_at(1700000000));
+        assert!(timestamped_hash.is_expired_at(1700003600));
+        assert!(TimestampedDispnetHash::new(DispnetHash::new("test".as_bytes()), None).unwrap().expires.is_none());
+    }
+
+    #[test]
+    fn expiry_round_trip() {
+        for expires in [None, Some(1), Some(u64::MAX)] {
+            let timestamped_hash = TimestampedDispnetHash::with_timestamp(DispnetHash::new("test".as_bytes()), 0, expires).unwrap();
+            assert_eq!(timestamped_hash.to_string().parse::<TimestampedDispnetHash>().unwrap(), timestamped_hash);
+        }
+        assert!(matches!(
+            TimestampedDispnetHash::with_timestamp(DispnetHash::new("test".as_bytes()), 1700000000, Some(0)),
+            Err(HashError::InvalidTimestamp { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_timestamped_hash() {
+        let timestamped_hash = "00000000001700000000000000000000000000000100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215".parse::<TimestampedDispnetHash>().unwrap();
+        assert_eq!(timestamped_hash.created, 1700000000);
+        assert_eq!(timestamped_hash.expires, None);
+        assert_eq!(timestamped_hash.hash, DispnetHash::new("test".as_bytes()));
+        assert!(!timestamped_hash.is_expired());
+        assert!(matches!("00000000001700000000".parse::<TimestampedDispnetHash>(), Err(HashError::InvalidTimestamp { .. })));
+    }
+}
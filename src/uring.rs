@@ -0,0 +1,160 @@
+//! io_uring reader for [`crate::hash_file`] on Linux (feature `io-uring`).
+//!
+//! Keeps `QUEUE_DEPTH` reads in flight while the completed buffers are hashed in file order,
+//! the disk reads overlap with the hashing.
+use std::{fs::File, io, os::fd::AsRawFd};
+
+use io_uring::{opcode, types, IoUring};
+
+use crate::DispnetHasher;
+
+const QUEUE_DEPTH: usize = 4;
+const BUFFER_LEN: usize = 256 * 1024;
+
+struct Slot {
+    buffer: Vec<u8>,
+    offset: u64,
+    filled: usize,
+    result: Option<i32>,
+}
+
+struct UringReader {
+    ring: IoUring,
+    fd: types::Fd,
+    slots: Vec<Slot>,
+    in_flight: usize,
+}
+
+/// Hash the content of the file, `None` if io_uring is not available (e.g. blocked by seccomp or an old kernel).
+/// The file position is not used, the reads are positioned.
+pub(crate) fn hash_file(file: &File, hasher: &mut DispnetHasher) -> Option<io::Result<()>> {
+    let ring = IoUring::new(QUEUE_DEPTH as u32).ok()?;
+    let slots = (0..QUEUE_DEPTH)
+        .map(|_| Slot {
+            buffer: vec![0; BUFFER_LEN],
+            offset: 0,
+            filled: 0,
+            result: None,
+        })
+        .collect();
+    let mut reader = UringReader {
+        ring,
+        fd: types::Fd(file.as_raw_fd()),
+        slots,
+        in_flight: 0,
+    };
+    Some(reader.hash(hasher))
+}
+
+impl UringReader {
+    fn hash(&mut self, hasher: &mut DispnetHasher) -> io::Result<()> {
+        let mut next_offset = 0;
+        for index in 0..QUEUE_DEPTH {
+            self.slots[index].offset = next_offset;
+            next_offset += BUFFER_LEN as u64;
+            self.submit(index)?;
+        }
+        let mut current = 0;
+        loop {
+            let result = self.wait_for(current)?;
+            if result < 0 {
+                let error = io::Error::from_raw_os_error(-result);
+                if error.kind() == io::ErrorKind::Interrupted {
+                    self.submit(current)?;
+                    continue;
+                }
+                return Err(error);
+            }
+            let slot = &mut self.slots[current];
+            slot.filled += result as usize;
+            // A short read is not the end of the file, read the rest of the buffer.
+            if result > 0 && slot.filled < BUFFER_LEN {
+                self.submit(current)?;
+                continue;
+            }
+            hasher.update(&slot.buffer[..slot.filled]);
+            if result == 0 {
+                return Ok(());
+            }
+            slot.offset = next_offset;
+            slot.filled = 0;
+            next_offset += BUFFER_LEN as u64;
+            self.submit(current)?;
+            current = (current + 1) % QUEUE_DEPTH;
+        }
+    }
+
+    /// Read into the unfilled part of the slot buffer.
+    fn submit(&mut self, index: usize) -> io::Result<()> {
+        let slot = &mut self.slots[index];
+        slot.result = None;
+        let unfilled = &mut slot.buffer[slot.filled..];
+        let entry = opcode::Read::new(self.fd, unfilled.as_mut_ptr(), unfilled.len() as u32)
+            .offset(slot.offset + slot.filled as u64)
+            .build()
+            .user_data(index as u64);
+        // SAFETY: the buffer is not touched until the read is completed, `Drop` waits for all reads in flight.
+        unsafe { self.ring.submission().push(&entry) }.map_err(io::Error::other)?;
+        self.in_flight += 1;
+        self.ring.submit()?;
+        Ok(())
+    }
+
+    fn wait_for(&mut self, index: usize) -> io::Result<i32> {
+        loop {
+            if let Some(result) = self.slots[index].result.take() {
+                return Ok(result);
+            }
+            self.ring.submit_and_wait(1)?;
+            self.complete();
+        }
+    }
+
+    fn complete(&mut self) {
+        for entry in self.ring.completion() {
+            self.slots[entry.user_data() as usize].result = Some(entry.result());
+            self.in_flight -= 1;
+        }
+    }
+}
+
+impl Drop for UringReader {
+    fn drop(&mut self) {
+        while self.in_flight > 0 {
+            match self.ring.submit_and_wait(1) {
+                Ok(_) => self.complete(),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => {
+                    // The kernel might still write into the buffers, never free them.
+                    std::mem::forget(std::mem::take(&mut self.slots));
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+
+    use crate::{DispnetHash, DispnetHasher, HashType};
+
+    #[test]
+    fn uring_matches_create() {
+        let path = std::env::temp_dir().join(format!("dispnet-hash-{}-uring", std::process::id()));
+        for length in [0, 1000, super::BUFFER_LEN, super::BUFFER_LEN * super::QUEUE_DEPTH * 2 + 17] {
+            let content: Vec<u8> = (0..length).map(|i| (i % 251) as u8).collect();
+            fs::write(&path, &content).unwrap();
+            let mut hasher = DispnetHasher::new(HashType::Blake3, None);
+            let Some(result) = super::hash_file(&File::open(&path).unwrap(), &mut hasher) else {
+                // io_uring is not available in this environment.
+                break;
+            };
+            result.unwrap();
+            assert_eq!(hasher.finalize(), DispnetHash::create(HashType::Blake3, &content, None));
+            assert_eq!(crate::hash_file(&path, HashType::Blake3).unwrap(), DispnetHash::create(HashType::Blake3, &content, None));
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::borrow::ToOwned;
+
+use crate::{digest_fits_type, DispnetHash, HashError, HashType, HEX_VALUES, INVALID_HEX};
+
+/// Format issue of a hash string, the error detail is only built by [`DispnetHash::validate`].
+enum FormatIssue {
+    Header,
+    Digest,
+    DigestLength,
+    DigestLengthMissmatch { length: usize },
+    DigestForType { hash_type: HashType, length: usize },
+}
+
+/// Check the display format like [`DispnetHash::from_str`](std::str::FromStr) without decoding the digest.
+fn format_issue(hash_value: &str) -> Option<FormatIssue> {
+    let bytes = hash_value.as_bytes();
+    if bytes.len() < 6 || !hash_value.is_char_boundary(2) || !hash_value.is_char_boundary(6) {
+        return Some(FormatIssue::Header);
+    }
+    let hex_digest = &bytes[6..];
+    if !hex_digest.len().is_multiple_of(2) || hex_digest.iter().any(|b| HEX_VALUES[*b as usize] == INVALID_HEX) {
+        return Some(FormatIssue::Digest);
+    }
+    if !bytes[2..6].iter().all(u8::is_ascii_digit) {
+        return Some(FormatIssue::DigestLength);
+    }
+    let length = bytes[2..6].iter().fold(0, |length, digit| length * 10 + (digit - b'0') as usize);
+    if length * 2 != hex_digest.len() {
+        return Some(FormatIssue::DigestLengthMissmatch { length });
+    }
+    // Unknown hash types are parsed as Blake3.
+    let hash_type = DispnetHash::detect_type(hash_value).unwrap_or(HashType::Blake3);
+    let digest = hex_digest
+        .chunks_exact(2)
+        .map(|pair| (HEX_VALUES[pair[0] as usize] << 4) | HEX_VALUES[pair[1] as usize]);
+    if !digest_fits_type(hash_type, digest) {
+        return Some(FormatIssue::DigestForType { hash_type, length });
+    }
+    None
+}
+
+impl DispnetHash {
+    /// Check the format of a hash string without building the hash.
+    /// Accepts the same strings as parsing, nothing is allocated for valid hashes.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashError};
+    ///
+    /// fn validate_request(raw_hash: &str) -> Result<(), HashError> {
+    ///     DispnetHash::validate(raw_hash)
+    /// }
+    /// ```
+    pub fn validate(hash_value: &str) -> Result<(), HashError> {
+        match format_issue(hash_value) {
+            None => Ok(()),
+            Some(FormatIssue::Header) => Err(HashError::DigestLength {
+                raw_digest_length: hash_value.to_owned(),
+            }),
+            Some(FormatIssue::Digest) => Err(HashError::InvalidDigest {
+                hex_digest: hash_value[6..].to_owned(),
+            }),
+            Some(FormatIssue::DigestLength) => Err(HashError::DigestLength {
+                raw_digest_length: hash_value[2..6].to_owned(),
+            }),
+            Some(FormatIssue::DigestForType { hash_type, length }) => Err(HashError::InvalidDigestForType { hash_type, length }),
+            Some(FormatIssue::DigestLengthMissmatch { length }) => Err(HashError::DigestLengthMissmatch {
+                length,
+                digest: DispnetHash::hex_to_bytes(&hash_value[6..]).unwrap_or_default(),
+            }),
+        }
+    }
+
+    /// Check if a string is a well-formed hash (see [`DispnetHash::validate`]), without allocating.
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::DispnetHash;
+    ///
+    /// fn hashes_in_log(log: &str) -> Vec<&str> {
+    ///     log.split_whitespace().filter(|word| DispnetHash::is_valid(word)).collect()
+    /// }
+    /// ```
+    pub fn is_valid(hash_value: &str) -> bool {
+        format_issue(hash_value).is_none()
+    }
+
+    /// Hash type from the header of a hash string, the digest is not checked.
+    /// Returns `None` for unknown hash types (parsing would fall back to Blake3).
+    /// # Usage
+    /// ```
+    /// use dispnet_hash::{DispnetHash, HashType};
+    ///
+    /// fn is_password_hash(raw_hash: &str) -> bool {
+    ///     DispnetHash::detect_type(raw_hash) == Some(HashType::Argon2)
+    /// }
+    /// ```
+    pub fn detect_type(hash_value: &str) -> Option<HashType> {
+        match hash_value.as_bytes() {
+            [tens @ b'0'..=b'9', ones @ b'0'..=b'9', ..] => HashType::from_code((tens - b'0') * 10 + (ones - b'0')),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DispnetHash, HashError, HashType};
+
+    #[test]
+    fn validate() {
+        for valid_hash in [
+            "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215",
+            "0100324878CA0425C739FA427F7EDA20FE845F6B2E46BA5FE2A14DF5B1E32F50603215",
+            "02001032323538363632303830",
+        ] {
+            assert!(DispnetHash::validate(valid_hash).is_ok());
+            assert!(valid_hash.parse::<DispnetHash>().is_ok());
+        }
+        assert!(matches!(DispnetHash::validate("0100"), Err(HashError::DigestLength { .. })));
+        assert!(matches!(DispnetHash::validate("0ä0032"), Err(HashError::DigestLength { .. })));
+        assert!(matches!(DispnetHash::validate("01000ä74"), Err(HashError::DigestLength { .. })));
+        assert!(matches!(DispnetHash::validate("01000174g"), Err(HashError::InvalidDigest { .. })));
+        assert!(matches!(DispnetHash::validate("01x00174"), Err(HashError::DigestLength { .. })));
+        let signed_length = "01+0324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215";
+        assert!(matches!(DispnetHash::validate(signed_length), Err(HashError::DigestLength { .. })));
+        assert!(matches!(signed_length.parse::<DispnetHash>(), Err(HashError::DigestLength { .. })));
+        assert!(DispnetHash::is_valid("02001032323538363632303830"));
+        assert!(!DispnetHash::is_valid("020010323235"));
+        assert!(matches!(DispnetHash::validate("0100037465"), Err(HashError::DigestLengthMissmatch { length: 3, .. })));
+        assert!(matches!(DispnetHash::validate("0100027465"), Err(HashError::InvalidDigestForType { hash_type: HashType::Blake3, length: 2 })));
+    }
+
+    #[test]
+    fn detect_type() {
+        assert_eq!(DispnetHash::detect_type("02001032323538363632303830"), Some(HashType::CRC));
+        assert_eq!(DispnetHash::detect_type("03"), Some(HashType::Argon2));
+        assert_eq!(DispnetHash::detect_type("09001032"), None);
+        assert_eq!(DispnetHash::detect_type("0"), None);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::Write;
+
+use crate::{DispnetHash, HashType};
+
+/// Expected hashes of the test vector inputs (index into [`test_vector_inputs`]) with the default config.
+const EXPECTED_HASHES: &[(usize, HashType, &str)] = &[
+    (0, HashType::Blake3, "010032af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+    (0, HashType::CRC, "02000130"),
+    (0, HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e5568716147637924646256376159334433316d4d75486d774370656d5962646f7474654b4e4b466e79576571764f51492f366f"),
+    (0, HashType::SimHash, "0400080000000000000000"),
+    (0, HashType::GitSha1, "050020e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+    (0, HashType::CrcIeee, "06000130"),
+    (0, HashType::CrcKoopman, "07000130"),
+    (1, HashType::Blake3, "0100324878ca0425c739fa427f7eda20fe845f6b2e46ba5fe2a14df5b1e32f50603215"),
+    (1, HashType::CRC, "02001032323538363632303830"),
+    (1, HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e5568716147637924464d4f7a6f46647754464676397a31435a485751684b7a2f63696f754c55427571494a54756a574d375338"),
+    (1, HashType::SimHash, "040008f5b1e32f50603215"),
+    (1, HashType::GitSha1, "05002030d74d258442c7c65512eafab474568dd706c430"),
+    (1, HashType::CrcIeee, "06001033363332323333393936"),
+    (1, HashType::CrcKoopman, "07001031353437323833323330"),
+    (2, HashType::Blake3, "0100322f1514181aadccd913abd94cfa592701a5686ab23f8df1dff1b74710febc6d4a"),
+    (2, HashType::CRC, "020009353736383438393030"),
+    (2, HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e55687161476379247635714d4568326851305133596c7974323830667a6d31665332626453466b32632b557a625a50647a4f45"),
+    (2, HashType::SimHash, "040008baac26edd28b664f"),
+    (2, HashType::GitSha1, "050020ff3bb63948b4b24796d2acd259915f2a9d972638"),
+    (2, HashType::CrcIeee, "06001031303935373338313639"),
+    (2, HashType::CrcKoopman, "07001033373630333135323830"),
+    (3, HashType::Blake3, "0100324a495ba42461748eca8fdad618f976aa726cc2903de9fcb40735a786ac1c196b"),
+    (3, HashType::CRC, "02001032363231373038333633"),
+    (3, HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e556871614763792459613168616d6a59374e7378396439526f2f53355062702b6e70502f324c6677582f71504676354b693441"),
+    (3, HashType::SimHash, "040008e35882d0c0b00840"),
+    (3, HashType::GitSha1, "050020c86626638e0bc8cf47ca49bb1525b40e9737ee64"),
+    (3, HashType::CrcIeee, "060009363838323239343931"),
+    (3, HashType::CrcKoopman, "07001032383831353430393636"),
+    (4, HashType::Blake3, "010032e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a"),
+    (4, HashType::CRC, "02001032363735383438313736"),
+    (4, HashType::Argon2, "030121246172676f6e326924763d3139246d3d343039362c743d332c703d31245154687556586f785547746a4d456c614d48564b5531704f626b3173646d524d656a42554d3246734e55687161476379242b63775841377161564b432b4475376447676d534235425079444269444f474b65415173374e616d644830"),
+    (4, HashType::SimHash, "040008e3bd36f449964240"),
+    (4, HashType::GitSha1, "050020aa43aa7eed779fc55d403989d2157a630c425f4b"),
+    (4, HashType::CrcIeee, "06001033373131323136393933"),
+    (4, HashType::CrcKoopman, "07001033393037323833353039"),
+];
+
+/// Reference test vector of the dispnet format (see [`test_vectors`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestVector {
+    /// Short description of the input.
+    pub name: &'static str,
+    pub input: Vec<u8>,
+    pub hash_type: HashType,
+    /// Expected hash in the display format (created with the default config).
+    pub formatted: &'static str,
+}
+
+impl TestVector {
+    /// Expected digest as hex (without the type and length header).
+    pub fn digest_hex(&self) -> &'static str {
+        &self.formatted[6..]
+    }
+
+    /// True if the hash of the input matches the expected hash.
+    #[must_use]
+    pub fn verify(&self) -> bool {
+        DispnetHash::create(self.hash_type, &self.input, None).to_string() == self.formatted
+    }
+}
+
+fn test_vector_inputs() -> [(&'static str, Vec<u8>); 5] {
+    [
+        ("empty", Vec::new()),
+        ("test", b"test".to_vec()),
+        ("quick-brown-fox", b"The quick brown fox jumps over the lazy dog".to_vec()),
+        ("all-bytes", (0..=255).collect()),
+        // Longer than one Blake3 chunk (1024 bytes).
+        ("pattern-2048", (0..2048).map(|i| (i % 251) as u8).collect()),
+    ]
+}
+
+/// Reference test vectors of every input and hash type, for validating other implementations
+/// of the dispnet format.
+/// # Usage
+/// ```
+/// use dispnet_hash::test_vectors;
+///
+/// fn validate() {
+///     assert!(test_vectors().iter().all(|test_vector| test_vector.verify()));
+/// }
+/// ```
+pub fn test_vectors() -> Vec<TestVector> {
+    let inputs = test_vector_inputs();
+    EXPECTED_HASHES
+        .iter()
+        .map(|(input_index, hash_type, formatted)| TestVector {
+            name: inputs[*input_index].0,
+            input: inputs[*input_index].1.clone(),
+            hash_type: *hash_type,
+            formatted,
+        })
+        .collect()
+}
+
+/// Test vectors as JSON.
+///
+/// # JSON format is structured as followed:
+///
+/// `{"vectors":[{"name":..,"input_hex":..,"hash_type":..,"type_code":..,"digest_hex":..,"formatted":..}, ..]}`
+/// where `hash_type` is the name (e.g. `Blake3`) and `type_code` the number of the hash type.
+pub fn test_vectors_json() -> String {
+    let mut json = String::from("{\"vectors\":[");
+    for (index, test_vector) in test_vectors().iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        // All values are ASCII names, numbers and hex, nothing needs escaping.
+        write!(
+            json,
+            "{{\"name\":\"{}\",\"input_hex\":\"{}\",\"hash_type\":\"{:?}\",\"type_code\":{},\"digest_hex\":\"{}\",\"formatted\":\"{}\"}}",
+            test_vector.name,
+            DispnetHash::bytes_to_hex(&test_vector.input),
+            test_vector.hash_type,
+            test_vector.hash_type.code(),
+            test_vector.digest_hex(),
+            test_vector.formatted
+        )
+        .unwrap();
+    }
+    json.push_str("]}");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_vectors, test_vectors_json, DispnetHash};
+
+    #[test]
+    fn test_vectors_match() {
+        let test_vectors = test_vectors();
+        assert_eq!(test_vectors.len(), 35);
+        for test_vector in &test_vectors {
+            assert!(test_vector.verify(), "{:?}", test_vector);
+            assert_eq!(test_vector.formatted.parse::<DispnetHash>().unwrap().hash_type, test_vector.hash_type);
+        }
+    }
+
+    #[test]
+    fn test_vectors_export() {
+        let json = test_vectors_json();
+        assert!(json.starts_with(r#"{"vectors":[{"name":"empty","input_hex":"","hash_type":"Blake3","type_code":1,"digest_hex":"af1349b9"#));
+        assert_eq!(json.matches("\"formatted\"").count(), 35);
+        #[cfg(feature = "serde")]
+        {
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["vectors"][1]["formatted"], test_vectors()[1].formatted);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::{self, Read};
+
+use crate::{DispnetHash, DispnetHasher};
+
+/// Reader which hashes everything it reads and fails at the end if the hash does not match.
+///
+/// The end of the inner reader is only passed on if the content matches the expected hash,
+/// otherwise the read returns an `InvalidData` error (also for every later read). Code which
+/// reads until the end (e.g. `read_to_end`, [`io::copy`]) therefore fails on corrupted content.
+/// Argon2 hashes are salted and can not be verified while reading.
+///
+/// # Usage
+/// ```
+/// use std::io::Read;
+///
+/// use dispnet_hash::{DispnetHash, VerifiedReader};
+///
+/// fn read_verified() {
+///     let expected_hash = DispnetHash::new("test".as_bytes());
+///     let mut content = String::new();
+///     VerifiedReader::new("test".as_bytes(), expected_hash).read_to_string(&mut content).unwrap();
+///
+///     let expected_hash = DispnetHash::new("other".as_bytes());
+///     assert!(VerifiedReader::new("test".as_bytes(), expected_hash).read_to_string(&mut content).is_err());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct VerifiedReader<R> {
+    inner: R,
+    expected_hash: DispnetHash,
+    hasher: Option<DispnetHasher>,
+    verified: Option<bool>,
+}
+
+impl<R: Read> VerifiedReader<R> {
+    pub fn new(inner: R, expected_hash: DispnetHash) -> Self {
+        Self {
+            hasher: Some(DispnetHasher::new(expected_hash.hash_type, None)),
+            inner,
+            expected_hash,
+            verified: None,
+        }
+    }
+
+    /// `Some(true)` once the end was reached with a matching hash, `None` before the end.
+    pub fn is_verified(&self) -> Option<bool> {
+        self.verified
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn mismatch(&self) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("content does not match the expected hash {}", self.expected_hash),
+        )
+    }
+}
+
+impl<R: Read> Read for VerifiedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.verified == Some(false) {
+            return Err(self.mismatch());
+        }
+        let read = self.inner.read(buf)?;
+        if read > 0 {
+            if let Some(hasher) = self.hasher.as_mut() {
+                hasher.update(&buf[..read]);
+            }
+        } else if !buf.is_empty() {
+            if let Some(hasher) = self.hasher.take() {
+                self.verified = Some(hasher.finalize() == self.expected_hash);
+            }
+            if self.verified == Some(false) {
+                return Err(self.mismatch());
+            }
+        }
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use crate::{DispnetHash, HashType, VerifiedReader};
+
+    #[test]
+    fn verified_read() {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        let mut reader = VerifiedReader::new(data.as_slice(), DispnetHash::create(HashType::CRC, &data, None));
+        let mut content = Vec::new();
+        reader.read_to_end(&mut content).unwrap();
+        assert_eq!(content, data);
+        assert_eq!(reader.is_verified(), Some(true));
+        assert_eq!(reader.read(&mut [0; 8]).unwrap(), 0);
+    }
+
+    #[test]
+    fn corrupted_read() {
+        let mut reader = VerifiedReader::new("corrupted".as_bytes(), DispnetHash::new("test".as_bytes()));
+        let mut buffer = [0; 64];
+        assert_eq!(reader.read(&mut buffer).unwrap(), 9);
+        assert_eq!(reader.is_verified(), None);
+        assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.is_verified(), Some(false));
+        assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert!(io::copy(&mut VerifiedReader::new("corrupted".as_bytes(), DispnetHash::new("test".as_bytes())), &mut io::sink()).is_err());
+    }
+}
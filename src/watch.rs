@@ -0,0 +1,226 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{hash_file, DispnetHash, HashType};
+
+/// Change of a file hash detected by the [`HashWatcher`].
+/// `old_hash` is `None` for new files, `new_hash` is `None` for removed files.
+#[derive(Debug, PartialEq)]
+pub struct HashChange {
+    pub path: PathBuf,
+    pub old_hash: Option<DispnetHash>,
+    pub new_hash: Option<DispnetHash>,
+}
+
+/// Watch a file or directory (recursive) and re-hash files when they change.
+///
+/// All files are hashed when the watcher is created, afterwards every change event re-hashes the
+/// affected file and emits a [`HashChange`] if the hash is different. A created or moved in directory
+/// emits the changes of all files in it, a removed or moved out directory the removal of all its files.
+///
+/// # Usage
+/// ```
+/// use dispnet_hash::{HashType, HashWatcher};
+///
+/// fn watch_directory() {
+///     let watcher = HashWatcher::new("data", HashType::Blake3).unwrap();
+///     for change in watcher.changes() {
+///         println!("{:?}: {:?} -> {:?}", change.path, change.old_hash, change.new_hash);
+///     }
+/// }
+/// ```
+pub struct HashWatcher {
+    _watcher: RecommendedWatcher,
+    changes: Receiver<HashChange>,
+}
+
+impl HashWatcher {
+    /// Hash all files below the path and start watching it.
+    /// The paths of the changes are absolute (the path is canonicalized like the paths of the events).
+    pub fn new(path: impl AsRef<Path>, hash_type: HashType) -> io::Result<Self> {
+        let path = &path.as_ref().canonicalize()?;
+        let mut files = Vec::new();
+        collect_files(path, &mut files)?;
+        let mut hashes = HashMap::new();
+        for file in files {
+            let hash = hash_file(&file, hash_type)?;
+            hashes.insert(file, hash);
+        }
+        let hashes = Arc::new(Mutex::new(hashes));
+        let (sender, changes) = channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event {
+                rehash(event, hash_type, &hashes, &sender);
+            }
+        })
+        .map_err(io::Error::other)?;
+        watcher
+            .watch(path, RecursiveMode::Recursive)
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    /// Receiver of the detected hash changes.
+    pub fn changes(&self) -> &Receiver<HashChange> {
+        &self.changes
+    }
+}
+
+/// Collect the files below the path.
+/// Symlinks to files are collected, symlinked directories are skipped (they could form a cycle).
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        for entry in fs::read_dir(path)? {
+            collect_files(&entry?.path(), files)?;
+        }
+    } else if path.is_file() {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+fn rehash(
+    event: Event,
+    hash_type: HashType,
+    hashes: &Mutex<HashMap<PathBuf, DispnetHash>>,
+    sender: &Sender<HashChange>,
+) {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+    let mut hashes = hashes.lock().unwrap();
+    for path in event.paths {
+        let is_dir = fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.is_dir());
+        let changed_files = if is_dir || path.is_file() {
+            // A created or moved in directory has no events for the files in it.
+            let mut files = Vec::new();
+            let _ = collect_files(&path, &mut files);
+            files
+        } else {
+            // A removed or moved out directory removes all files below it.
+            hashes.keys().filter(|known_path| known_path.starts_with(&path)).cloned().collect()
+        };
+        for path in changed_files {
+            let new_hash = if path.is_file() {
+                // Keep the old hash if the file can not be read right now, it is not removed.
+                let Ok(new_hash) = hash_file(&path, hash_type) else {
+                    continue;
+                };
+                Some(new_hash)
+            } else {
+                None
+            };
+            if hashes.get(&path) == new_hash.as_ref() {
+                continue;
+            }
+            let old_hash = match &new_hash {
+                Some(new_hash) => hashes.insert(path.clone(), new_hash.clone()),
+                None => hashes.remove(&path),
+            };
+            // The receiver might be dropped while the watcher is still running.
+            let _ = sender.send(HashChange {
+                path,
+                old_hash,
+                new_hash,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use crate::{DispnetHash, HashChange, HashType, HashWatcher};
+
+    #[test]
+    fn watch_file_changes() {
+        let directory = std::env::temp_dir().join(format!("dispnet-hash-{}-watch", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("file.txt");
+        fs::write(&path, "old").unwrap();
+        let path = path.canonicalize().unwrap();
+
+        let watcher = HashWatcher::new(directory.canonicalize().unwrap(), HashType::CRC).unwrap();
+        fs::write(&path, "new").unwrap();
+        let new_hash = DispnetHash::create(HashType::CRC, "new".as_bytes(), None);
+        // Writing can be observed as truncate and write, the first change starts at the old content.
+        let mut changes = std::iter::from_fn(|| watcher.changes().recv_timeout(Duration::from_secs(5)).ok());
+        let first_change = changes.next().unwrap();
+        assert_eq!(first_change.path, path);
+        assert_eq!(first_change.old_hash, Some(DispnetHash::create(HashType::CRC, "old".as_bytes(), None)));
+        if first_change.new_hash.as_ref() != Some(&new_hash) {
+            assert!(changes.any(|change| change.new_hash.as_ref() == Some(&new_hash)));
+        }
+
+        fs::remove_file(&path).unwrap();
+        let change = watcher.changes().recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change.new_hash, None);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_cycle() {
+        let directory = std::env::temp_dir().join(format!("dispnet-hash-{}-watch-symlink", std::process::id()));
+        fs::create_dir_all(directory.join("nested")).unwrap();
+        fs::write(directory.join("nested/file.txt"), "test").unwrap();
+        std::os::unix::fs::symlink(&directory, directory.join("nested/parent")).unwrap();
+        std::os::unix::fs::symlink(directory.join("nested/file.txt"), directory.join("link.txt")).unwrap();
+
+        let mut paths = Vec::new();
+        super::collect_files(&directory, &mut paths).unwrap();
+        paths.sort();
+        assert_eq!(paths, [directory.join("link.txt"), directory.join("nested/file.txt")]);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn watch_directory_moves() {
+        let directory = std::env::temp_dir().join(format!("dispnet-hash-{}-watch-directory", std::process::id()));
+        let outside = std::env::temp_dir().join(format!("dispnet-hash-{}-watch-outside", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::create_dir_all(outside.join("nested")).unwrap();
+        fs::write(outside.join("nested/file.txt"), "test").unwrap();
+        let directory = directory.canonicalize().unwrap();
+        let hash = DispnetHash::create(HashType::CRC, "test".as_bytes(), None);
+
+        let watcher = HashWatcher::new(&directory, HashType::CRC).unwrap();
+        fs::rename(outside.join("nested"), directory.join("nested")).unwrap();
+        let change = watcher.changes().recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change, HashChange { path: directory.join("nested/file.txt"), old_hash: None, new_hash: Some(hash.clone()) });
+
+        fs::rename(directory.join("nested"), outside.join("nested")).unwrap();
+        let change = watcher.changes().recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change, HashChange { path: directory.join("nested/file.txt"), old_hash: Some(hash), new_hash: None });
+        fs::remove_dir_all(&directory).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+
+    #[test]
+    fn watch_relative_path() {
+        let directory = format!("target/dispnet-hash-{}-watch-relative", std::process::id());
+        fs::create_dir_all(&directory).unwrap();
+        let path = format!("{}/file.txt", directory);
+        fs::write(&path, "old").unwrap();
+
+        let watcher = HashWatcher::new(&directory, HashType::CRC).unwrap();
+        fs::write(&path, "new").unwrap();
+        let change = watcher.changes().recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change.path, fs::canonicalize(&path).unwrap());
+        assert_eq!(change.old_hash, Some(DispnetHash::create(HashType::CRC, "old".as_bytes(), None)));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
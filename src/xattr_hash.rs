@@ -0,0 +1,70 @@
+use std::{io, path::Path};
+
+use crate::{hash_file, DispnetHash};
+
+/// Name of the extended attribute which stores the dispnet hash of a file.
+pub const HASH_XATTR_NAME: &str = "user.dispnet.hash";
+
+/// Store the hash (display format) in the extended attribute [`HASH_XATTR_NAME`] of the file.
+/// # Usage
+/// ```
+/// use dispnet_hash::{hash_file, store_hash_xattr, verify_xattr, HashType};
+///
+/// fn seal(path: &str) {
+///     let dispnet_hash = hash_file(path, HashType::Blake3).unwrap();
+///     store_hash_xattr(path, &dispnet_hash).unwrap();
+///     assert!(verify_xattr(path).unwrap());
+/// }
+/// ```
+pub fn store_hash_xattr(path: impl AsRef<Path>, hash: &DispnetHash) -> io::Result<()> {
+    xattr::set(path, HASH_XATTR_NAME, hash.to_string().as_bytes())
+}
+
+/// Hash stored in the extended attribute [`HASH_XATTR_NAME`] of the file, `None` if it is not set.
+/// An attribute which is not a valid dispnet hash is an `InvalidData` error.
+pub fn read_hash_xattr(path: impl AsRef<Path>) -> io::Result<Option<DispnetHash>> {
+    let Some(raw_hash) = xattr::get(path, HASH_XATTR_NAME)? else {
+        return Ok(None);
+    };
+    let hash = std::str::from_utf8(&raw_hash)
+        .ok()
+        .and_then(|raw_hash| raw_hash.parse::<DispnetHash>().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid dispnet hash in extended attribute"))?;
+    Ok(Some(hash))
+}
+
+/// Re-hash the file with the type of the stored hash and compare it with the extended attribute.
+/// A file without the attribute is a `NotFound` error.
+pub fn verify_xattr(path: impl AsRef<Path>) -> io::Result<bool> {
+    let path = path.as_ref();
+    let stored_hash = read_hash_xattr(path)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no dispnet hash extended attribute"))?;
+    Ok(hash_file(path, stored_hash.hash_type)? == stored_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io};
+
+    use crate::{hash_file, read_hash_xattr, store_hash_xattr, verify_xattr, HashType, HASH_XATTR_NAME};
+
+    #[test]
+    fn xattr_integrity() {
+        let path = std::env::temp_dir().join(format!("dispnet-hash-{}-xattr.txt", std::process::id()));
+        fs::write(&path, "test").unwrap();
+        assert_eq!(read_hash_xattr(&path).unwrap(), None);
+        assert_eq!(verify_xattr(&path).unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        let dispnet_hash = hash_file(&path, HashType::CRC).unwrap();
+        store_hash_xattr(&path, &dispnet_hash).unwrap();
+        assert_eq!(read_hash_xattr(&path).unwrap(), Some(dispnet_hash));
+        assert!(verify_xattr(&path).unwrap());
+
+        fs::write(&path, "changed").unwrap();
+        assert!(!verify_xattr(&path).unwrap());
+
+        xattr::set(&path, HASH_XATTR_NAME, b"invalid").unwrap();
+        assert_eq!(read_hash_xattr(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+}